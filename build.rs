@@ -0,0 +1,35 @@
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+// with the embedded-assets feature, lists everything in static/assets for the game to compile in
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    if env::var_os("CARGO_FEATURE_EMBEDDED_ASSETS").is_none() {
+        return;
+    }
+    let root = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("static/assets");
+    println!("cargo:rerun-if-changed={}", root.display());
+
+    let mut files = Vec::new();
+    collect_files(&root, &mut files);
+    files.sort();
+    let mut out = String::from("pub const ASSETS: &[(&str, &[u8])] = &[\n");
+    for file in files {
+        // asset paths always use forward slashes
+        let path: Vec<_> = file.strip_prefix(&root).unwrap().components().map(|part| part.as_os_str().to_string_lossy()).collect();
+        out += &format!("    ({:?}, include_bytes!({:?})),\n", path.join("/"), file.display().to_string());
+    }
+    out += "];\n";
+    fs::write(Path::new(&env::var("OUT_DIR").unwrap()).join("embedded_assets.rs"), out).unwrap();
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_files(&path, files);
+        } else {
+            files.push(path);
+        }
+    }
+}
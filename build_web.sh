@@ -1,11 +1,80 @@
 #!/bin/bash
 set -e
 
-# Build for web
-cargo build --profile web --target wasm32-unknown-unknown
+# Build for web, extra arguments go to cargo, e.g. --features embedded-assets
+cargo build --profile web --target wasm32-unknown-unknown --bin my_bevy_game "$@"
 
 # Generate JS bindings
 wasm-bindgen --target web --out-dir static --no-typescript \
     target/wasm32-unknown-unknown/web/my_bevy_game.wasm
 
-echo "Build complete! Files are in static/"
\ No newline at end of file
+# Generate the PWA manifest and a service worker that caches everything the game needs to run offline
+cd static
+files=(index.html my_bevy_game.js my_bevy_game_bg.wasm manifest.webmanifest icon-192.png icon-512.png)
+# embedded assets are inside the wasm already
+if [[ " $* " != *embedded-assets* ]]; then
+    while IFS= read -r -d '' asset; do
+        files+=("$asset")
+    done < <(find assets -type f -print0 | sort -z)
+fi
+
+cat > manifest.webmanifest <<MANIFEST
+{
+    "name": "Max's Dino Game",
+    "short_name": "Dino Runner",
+    "start_url": ".",
+    "display": "fullscreen",
+    "orientation": "landscape",
+    "background_color": "#000000",
+    "theme_color": "#000000",
+    "icons": [
+        { "src": "icon-192.png", "sizes": "192x192", "type": "image/png" },
+        { "src": "icon-512.png", "sizes": "512x512", "type": "image/png" }
+    ]
+}
+MANIFEST
+
+# the cache is named after the version and the content of this build, so a new build replaces the old cache
+version=$(grep -m1 '^version' ../Cargo.toml | cut -d '"' -f 2)
+build_hash=$(cat "${files[@]}" | sha256sum | cut -c 1-12)
+file_list=$(printf '    "%s",\n' "./" "${files[@]}")
+
+cat > sw.js <<SERVICE_WORKER
+// generated by build_web.sh
+const CACHE = "dino-runner-$version-$build_hash";
+const FILES = [
+$file_list
+];
+
+self.addEventListener("install", event => {
+    event.waitUntil(
+        caches.open(CACHE)
+            .then(cache => cache.addAll(FILES.map(encodeURI)))
+            .then(() => self.skipWaiting())
+    );
+});
+
+// drop the caches of older builds
+self.addEventListener("activate", event => {
+    event.waitUntil(
+        caches.keys()
+            .then(keys => Promise.all(keys
+                .filter(key => key.startsWith("dino-runner-") && key !== CACHE)
+                .map(key => caches.delete(key))))
+            .then(() => self.clients.claim())
+    );
+});
+
+// cache first, so the game starts without any network at all
+self.addEventListener("fetch", event => {
+    if (event.request.method !== "GET") {
+        return;
+    }
+    event.respondWith(
+        caches.match(event.request, { ignoreSearch: true })
+            .then(cached => cached || fetch(event.request))
+    );
+});
+SERVICE_WORKER
+
+echo "Build complete! Files are in static/"
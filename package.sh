@@ -0,0 +1,20 @@
+#!/bin/bash
+set -e
+
+# Builds self-contained releases into dist/, with every asset compiled in:
+#   dist/native/  a single executable that runs from any directory
+#   dist/web/     index.html, the JS glue, the wasm and the offline files, ready to upload as they are
+rm -rf dist
+mkdir -p dist/native dist/web
+
+cargo build --release --features embedded-assets --bin my_bevy_game
+for binary in target/release/my_bevy_game target/release/my_bevy_game.exe; do
+    if [ -f "$binary" ]; then
+        cp "$binary" "dist/native/dino-runner${binary##*my_bevy_game}"
+    fi
+done
+
+./build_web.sh --features embedded-assets
+cp static/index.html static/my_bevy_game.js static/my_bevy_game_bg.wasm static/sw.js static/manifest.webmanifest static/icon-*.png dist/web/
+
+echo "Packages are in dist/"
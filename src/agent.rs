@@ -0,0 +1,78 @@
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+// what an agent sees of the game and what it can do, the same for the game, its gym protocol and the trainer
+
+// how many of the obstacles ahead an agent is told about
+pub const OBSERVED_OBSTACLES: usize = 3;
+// frames an action is held for, a fifteenth of a second is about as quick as a person reacts
+pub const FRAMES_PER_STEP: u32 = 4;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ObstacleKind {
+    Cactus,
+    Pterodactyl,
+    Boulder,
+    LavaPit,
+    Meteor,
+}
+
+// held by an agent from one step to the next, like a key
+#[derive(Resource, Serialize, Deserialize, Default, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AgentAction {
+    #[default]
+    Noop,
+    Jump,
+    Duck,
+}
+
+// updated every frame when an agent is playing
+#[derive(Resource, Serialize, Deserialize, Default, Clone, Debug)]
+pub struct Observation {
+    pub player: PlayerObservation,
+    // the nearest ones that haven't been passed yet, closest first
+    pub obstacles: Vec<ObstacleObservation>,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Copy, Debug)]
+pub struct PlayerObservation {
+    // of the feet above the ground, in pixels
+    pub y: f32,
+    // upwards, in pixels per second
+    pub velocity: f32,
+    pub state: PlayerState,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PlayerState {
+    #[default]
+    Running,
+    Jumping,
+    Ducking,
+    Dead,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct ObstacleObservation {
+    pub kind: ObstacleKind,
+    // of the collider's centre, ahead of the player's feet and above the ground
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    // towards the player, in pixels per second of simulated time
+    pub speed: f32,
+}
+
+// the answer to a reset or a step
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Step {
+    pub observation: Observation,
+    // the distance covered in points, less the penalty on dying
+    pub reward: f32,
+    // the dino is dead, reset to play again
+    pub done: bool,
+}
@@ -0,0 +1,328 @@
+use bevy::asset::io::Reader;
+use bevy::asset::{Asset, AssetLoader, LoadContext};
+use bevy::prelude::*;
+use crate::components::ObstacleKind;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+// An asset described in a RON file, which may load further assets it refers to
+pub trait RonAsset: Asset + DeserializeOwned {
+    // catches values the file can hold but the game can't use, reported like any other load error
+    fn validate(&self) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn load_dependencies(&mut self, _load_context: &mut LoadContext) {}
+}
+
+pub struct RonAssetLoader<A> {
+    extensions: &'static [&'static str],
+    _asset: PhantomData<fn() -> A>,
+}
+
+impl<A> RonAssetLoader<A> {
+    pub fn new(extensions: &'static [&'static str]) -> Self {
+        RonAssetLoader {
+            extensions,
+            _asset: PhantomData,
+        }
+    }
+}
+
+impl<A: RonAsset> AssetLoader for RonAssetLoader<A> {
+    type Asset = A;
+    type Settings = ();
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<A, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let mut asset: A = ron::de::from_bytes(&bytes)?;
+        asset.validate().map_err(|err| format!("{}: {err}", load_context.path().display()))?;
+        asset.load_dependencies(load_context);
+        Ok(asset)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        self.extensions
+    }
+}
+
+#[derive(Asset, TypePath, Deserialize)]
+pub struct Biome {
+    pub name: String,
+    pub layers: Vec<BiomeLayerDesc>,
+    pub ground: GroundDesc,
+    pub obstacles: ObstaclePalette,
+}
+
+#[derive(Deserialize)]
+pub struct BiomeLayerDesc {
+    pub image: String,
+    #[serde(skip)]
+    pub handle: Handle<Image>,
+    #[serde(default)]
+    pub tiling: bool,
+    // relative to the ground, 0 for layers that don't scroll
+    #[serde(default)]
+    pub speed: f32,
+    #[serde(default)]
+    pub anchor: LayerAnchor,
+    #[serde(default)]
+    pub y_offset: f32,
+    pub z: f32,
+    #[serde(default = "white")]
+    pub tint: (f32, f32, f32),
+    #[serde(default)]
+    pub kind: LayerKind,
+}
+
+#[derive(Deserialize)]
+pub struct GroundDesc {
+    pub image: String,
+    #[serde(skip)]
+    pub handle: Handle<Image>,
+    // relative to GROUND_LEVEL
+    pub y_offset: f32,
+    #[serde(default = "white")]
+    pub tint: (f32, f32, f32),
+}
+
+#[derive(Deserialize, Default, Clone, Copy)]
+pub enum LayerAnchor {
+    #[default]
+    Center,
+    BottomCenter,
+}
+
+#[derive(Deserialize, Default, Clone, Copy, PartialEq)]
+pub enum LayerKind {
+    #[default]
+    Scenery,
+    Sky,
+    Sun,
+    Stars,
+}
+
+// relative spawn weights of everything that comes at the player
+#[derive(Deserialize, Clone)]
+pub struct ObstaclePalette {
+    #[serde(default)]
+    pub cactus: u32,
+    #[serde(default)]
+    pub boulder: u32,
+    #[serde(default)]
+    pub lava_pit: u32,
+    #[serde(default)]
+    pub meteor: u32,
+    #[serde(default)]
+    pub pterodactyl: u32,
+    #[serde(default)]
+    pub pickup: u32,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum SpawnKind {
+    Cactus,
+    Boulder,
+    LavaPit,
+    Meteor,
+    Pterodactyl,
+    Pickup,
+}
+
+// the desert mix, used until the first biome has loaded
+impl Default for ObstaclePalette {
+    fn default() -> Self {
+        ObstaclePalette {
+            cactus: 45,
+            boulder: 15,
+            lava_pit: 5,
+            meteor: 5,
+            pterodactyl: 15,
+            pickup: 15,
+        }
+    }
+}
+
+impl ObstaclePalette {
+    // picks a spawn kind with probability proportional to its weight
+    pub fn pick(&self, roll: u32) -> SpawnKind {
+        let weights = [
+            (SpawnKind::Cactus, self.cactus),
+            (SpawnKind::Boulder, self.boulder),
+            (SpawnKind::LavaPit, self.lava_pit),
+            (SpawnKind::Meteor, self.meteor),
+            (SpawnKind::Pterodactyl, self.pterodactyl),
+            (SpawnKind::Pickup, self.pickup),
+        ];
+        let total: u32 = weights.iter().map(|(_, weight)| weight).sum();
+        if total == 0 {
+            return SpawnKind::Cactus;
+        }
+        let mut roll = roll % total;
+        for (kind, weight) in weights {
+            if roll < weight {
+                return kind;
+            }
+            roll -= weight;
+        }
+        SpawnKind::Cactus
+    }
+}
+
+impl RonAsset for Biome {
+    fn load_dependencies(&mut self, load_context: &mut LoadContext) {
+        for layer in self.layers.iter_mut() {
+            layer.handle = load_context.load(&layer.image);
+        }
+        self.ground.handle = load_context.load(&self.ground.image);
+    }
+}
+
+// a burst of particles, ranges are picked from uniformly for each particle
+#[derive(Asset, TypePath, Deserialize)]
+pub struct EmitterConfig {
+    pub count: (u32, u32),
+    pub lifetime: (f32, f32),
+    pub speed: (f32, f32),
+    // in degrees, 0 to the right and 90 straight up
+    pub angle: (f32, f32),
+    #[serde(default)]
+    pub gravity: f32,
+    // fraction of the velocity lost per second
+    #[serde(default)]
+    pub drag: f32,
+    pub size: (f32, f32),
+    // width over height, to stretch particles into shapes like feathers
+    #[serde(default = "one")]
+    pub aspect: f32,
+    // in radians per second
+    #[serde(default)]
+    pub spin: (f32, f32),
+    pub colors: Vec<(f32, f32, f32)>,
+    // 1 to be left behind on the ground, 0 to move with the player
+    #[serde(default)]
+    pub scroll: f32,
+    #[serde(default)]
+    pub shrink: bool,
+}
+
+impl RonAsset for EmitterConfig {
+    fn validate(&self) -> Result<(), String> {
+        let ranges = [
+            ("count", (self.count.0 as f32, self.count.1 as f32)),
+            ("lifetime", self.lifetime),
+            ("speed", self.speed),
+            ("angle", self.angle),
+            ("size", self.size),
+            ("spin", self.spin),
+        ];
+        for (name, (min, max)) in ranges {
+            if !(min.is_finite() && max.is_finite() && min <= max) {
+                return Err(format!("{name} should be (min, max) with min no more than max, not ({min}, {max})"));
+            }
+        }
+        if self.lifetime.0 < 0.0 {
+            return Err("lifetime can't be negative".to_string());
+        }
+        if self.colors.is_empty() {
+            return Err("colors needs at least one colour".to_string());
+        }
+        Ok(())
+    }
+}
+
+// every achievement there is, in the order the achievements screen lists them
+#[derive(Asset, TypePath, Deserialize)]
+pub struct AchievementList {
+    pub achievements: Vec<AchievementDesc>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct AchievementDesc {
+    // saved in the profile once unlocked, so it mustn't change
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub condition: Condition,
+}
+
+// unlocked once the statistic reaches the target
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum Condition {
+    // within a single run
+    Run(RunStat, f32),
+    // added up over every run played
+    Lifetime(LifetimeStat, f32),
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum RunStat {
+    Score,
+    Seconds,
+    // as a multiple of the starting speed
+    TopSpeed,
+    Jumps,
+    Ducks,
+    Pickups,
+    NearMisses,
+    Cleared(ObstacleKind),
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum LifetimeStat {
+    Runs,
+    // in points, like the score
+    Distance,
+    PterodactylsKnockedDown,
+    CactusArmsDislodged,
+}
+
+impl RonAsset for AchievementList {}
+
+fn one() -> f32 {
+    1.0
+}
+
+fn white() -> (f32, f32, f32) {
+    (1.0, 1.0, 1.0)
+}
+
+pub fn tint_color((r, g, b): (f32, f32, f32)) -> Color {
+    Color::srgb(r, g, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shipped_emitters_are_valid() {
+        for file in ["crumbs", "dust", "feathers", "sparks"] {
+            let text = std::fs::read_to_string(format!("static/assets/particles/{file}.emitter.ron")).unwrap();
+            let config: EmitterConfig = ron::from_str(&text).unwrap();
+            assert_eq!(config.validate(), Ok(()), "{file}");
+        }
+    }
+
+    #[test]
+    fn rejects_emitters_that_would_panic() {
+        let emitter = |count: &str, colors: &str| {
+            ron::from_str::<EmitterConfig>(&format!(
+                "(count: {count}, lifetime: (0.2, 0.4), speed: (10.0, 20.0), angle: (0.0, 90.0), size: (2.0, 4.0), colors: {colors})"
+            ))
+            .unwrap()
+            .validate()
+        };
+        assert_eq!(emitter("(2, 5)", "[(1.0, 1.0, 1.0)]"), Ok(()));
+        assert!(emitter("(5, 2)", "[(1.0, 1.0, 1.0)]").is_err());
+        assert!(emitter("(2, 5)", "[]").is_err());
+    }
+}
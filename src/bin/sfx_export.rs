@@ -0,0 +1,65 @@
+// Writes the synthesized sound effects and music to WAV files, to listen to them outside the game.
+//
+// cargo run --bin sfx_export -- [OUT_DIR] [PRESET...]
+
+use my_bevy_game::synth::{self, SAMPLE_RATE};
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let out_dir = PathBuf::from(args.next().unwrap_or_else(|| "sfx".to_string()));
+    let mut wanted: Vec<String> = args.collect();
+    if wanted.is_empty() {
+        wanted = synth::PRESETS.iter().map(|(name, _)| name.to_string()).collect();
+        wanted.push("music".to_string());
+    }
+
+    if let Err(err) = fs::create_dir_all(&out_dir) {
+        eprintln!("can't create {}: {err}", out_dir.display());
+        return ExitCode::FAILURE;
+    }
+    for name in wanted {
+        let samples = if name == "music" {
+            synth::music()
+        } else if let Some((_, params)) = synth::PRESETS.iter().find(|(preset, _)| *preset == name) {
+            params.render()
+        } else {
+            let names: Vec<_> = synth::PRESETS.iter().map(|(name, _)| *name).collect();
+            eprintln!("unknown preset {name}, expected music or one of {}", names.join(", "));
+            return ExitCode::FAILURE;
+        };
+        let path = out_dir.join(format!("{name}.wav"));
+        if let Err(err) = write_wav(&path, &samples) {
+            eprintln!("can't write {}: {err}", path.display());
+            return ExitCode::FAILURE;
+        }
+        println!("{}", path.display());
+    }
+    ExitCode::SUCCESS
+}
+
+// 16 bit mono PCM
+fn write_wav(path: &Path, samples: &[f32]) -> io::Result<()> {
+    let data_len = samples.len() as u32 * 2;
+    let mut file = io::BufWriter::new(fs::File::create(path)?);
+    file.write_all(b"RIFF")?;
+    file.write_all(&(36 + data_len).to_le_bytes())?;
+    file.write_all(b"WAVEfmt ")?;
+    file.write_all(&16u32.to_le_bytes())?;
+    file.write_all(&1u16.to_le_bytes())?; // PCM
+    file.write_all(&1u16.to_le_bytes())?; // mono
+    file.write_all(&SAMPLE_RATE.to_le_bytes())?;
+    file.write_all(&(SAMPLE_RATE * 2).to_le_bytes())?; // bytes per second
+    file.write_all(&2u16.to_le_bytes())?; // bytes per frame
+    file.write_all(&16u16.to_le_bytes())?;
+    file.write_all(b"data")?;
+    file.write_all(&data_len.to_le_bytes())?;
+    for sample in samples {
+        let value = (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+        file.write_all(&value.to_le_bytes())?;
+    }
+    file.flush()
+}
@@ -0,0 +1,226 @@
+// Evolves networks that play the game, each worker a copy of the game running its gym protocol.
+//
+// cargo build --release && cargo run --release --bin trainer -- [--population N] [--generations N] [--seeds N]
+//     [--max-secs SECS] [--workers N] [--out FILE] [--seed N] [--game PATH] [--assets DIR]
+
+use my_bevy_game::agent::{AgentAction, Step, FRAMES_PER_STEP};
+use my_bevy_game::neural::Genome;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde_json::json;
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::process::{Child, ChildStdin, ChildStdout, Command, ExitCode, Stdio};
+use std::sync::Mutex;
+use std::thread;
+
+const USAGE: &str = "usage: trainer [--population N] [--generations N] [--seeds N] [--max-secs SECS] [--workers N] [--out FILE] [--seed N] [--game PATH] [--assets DIR]";
+
+// the best of each generation go on unchanged
+const ELITES: usize = 2;
+// parents are the fittest of this many picked at random
+const TOURNAMENT: usize = 3;
+const MUTATION_RATE: f32 = 0.1;
+const MUTATION_STRENGTH: f32 = 0.5;
+
+struct Options {
+    population: usize,
+    generations: usize,
+    // every genome plays the same runs, seeded 1 to this
+    seeds: u64,
+    // a run that goes on this long is stopped, a network that good has nothing left to learn from it
+    max_secs: f32,
+    workers: usize,
+    out: PathBuf,
+    seed: u64,
+    game: PathBuf,
+    assets: Option<String>,
+}
+
+impl Options {
+    fn from_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+        let mut options = Options {
+            population: 50,
+            generations: 30,
+            seeds: 3,
+            max_secs: 60.0,
+            workers: thread::available_parallelism().map_or(1, |cores| cores.get()),
+            out: PathBuf::from("best-genome.json"),
+            seed: 0,
+            game: game_next_to_trainer(),
+            assets: None,
+        };
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("{arg} needs a value"));
+            let number = |value: String| value.parse::<u64>().map_err(|_| format!("{arg} needs a whole number"));
+            match arg.as_str() {
+                "--population" => options.population = number(value()?)?.max(ELITES as u64 + 1) as usize,
+                "--generations" => options.generations = number(value()?)? as usize,
+                "--seeds" => options.seeds = number(value()?)?.max(1),
+                "--workers" => options.workers = number(value()?)?.max(1) as usize,
+                "--seed" => options.seed = number(value()?)?,
+                "--max-secs" => options.max_secs = value()?.parse().map_err(|_| "--max-secs needs a number of seconds")?,
+                "--out" => options.out = PathBuf::from(value()?),
+                "--game" => options.game = PathBuf::from(value()?),
+                "--assets" => options.assets = Some(value()?),
+                _ => return Err(format!("unknown argument {arg}")),
+            }
+        }
+        Ok(options)
+    }
+}
+
+// cargo puts all of a package's binaries in the same directory
+fn game_next_to_trainer() -> PathBuf {
+    let name = format!("my_bevy_game{}", std::env::consts::EXE_SUFFIX);
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(|dir| dir.join(&name)))
+        .unwrap_or_else(|| PathBuf::from(name))
+}
+
+// a copy of the game without a window, talking JSON lines
+struct Worker {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+impl Worker {
+    fn start(options: &Options) -> Result<Worker, String> {
+        let mut command = Command::new(&options.game);
+        command.args(["--gym", "stdio"]);
+        if let Some(assets) = &options.assets {
+            command.args(["--assets", assets]);
+        }
+        let mut child = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|err| format!("can't start {}: {err}", options.game.display()))?;
+        let (Some(stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            return Err("the game's stdin and stdout aren't piped".to_string());
+        };
+        Ok(Worker { child, stdin, stdout: BufReader::new(stdout) })
+    }
+
+    fn request(&mut self, request: serde_json::Value) -> Result<Step, String> {
+        writeln!(self.stdin, "{request}").and_then(|_| self.stdin.flush()).map_err(|err| format!("the game went away: {err}"))?;
+        let mut line = String::new();
+        match self.stdout.read_line(&mut line) {
+            Ok(0) | Err(_) => return Err("the game went away".to_string()),
+            Ok(_) => {}
+        }
+        serde_json::from_str(&line).map_err(|_| {
+            let error: Option<String> = serde_json::from_str::<serde_json::Value>(&line)
+                .ok()
+                .and_then(|reply| reply["error"].as_str().map(String::from));
+            error.unwrap_or_else(|| format!("the game answered {}", line.trim()))
+        })
+    }
+
+    // the total reward over a run from each seed
+    fn evaluate(&mut self, genome: &Genome, options: &Options) -> Result<f32, String> {
+        let max_steps = (options.max_secs * 60.0 / FRAMES_PER_STEP as f32) as usize;
+        let mut fitness = 0.0;
+        for seed in 1..=options.seeds {
+            let mut step = self.request(json!({"cmd": "reset", "seed": seed}))?;
+            for _ in 0..max_steps {
+                let action: AgentAction = genome.decide(&step.observation);
+                step = self.request(json!({"cmd": "step", "action": action}))?;
+                fitness += step.reward;
+                if step.done {
+                    break;
+                }
+            }
+        }
+        Ok(fitness)
+    }
+}
+
+impl Drop for Worker {
+    fn drop(&mut self) {
+        let _ = writeln!(self.stdin, "{}", json!({"cmd": "close"}));
+        let _ = self.child.wait();
+    }
+}
+
+// every genome's fitness, shared out between the workers
+fn evaluate_all(workers: &mut [Worker], genomes: &[Genome], options: &Options) -> Result<Vec<f32>, String> {
+    let jobs = Mutex::new(genomes.iter().enumerate());
+    let fitness = Mutex::new(vec![0.0; genomes.len()]);
+    thread::scope(|scope| {
+        let handles: Vec<_> = workers
+            .iter_mut()
+            .map(|worker| {
+                scope.spawn(|| loop {
+                    let Some((i, genome)) = jobs.lock().unwrap().next() else {
+                        return Ok(());
+                    };
+                    let score = worker.evaluate(genome, options)?;
+                    fitness.lock().unwrap()[i] = score;
+                })
+            })
+            .collect();
+        handles.into_iter().try_for_each(|handle| handle.join().unwrap_or(Err("a worker panicked".to_string())))
+    })?;
+    Ok(fitness.into_inner().unwrap())
+}
+
+fn tournament<'a>(ranked: &'a [(f32, Genome)], rng: &mut StdRng) -> &'a Genome {
+    // ranked best first, so the lowest index drawn wins
+    let best = (0..TOURNAMENT).map(|_| rng.gen_range(0..ranked.len())).min().unwrap_or_default();
+    &ranked[best].1
+}
+
+fn train(options: &Options) -> Result<(), String> {
+    let mut rng = StdRng::seed_from_u64(options.seed);
+    let mut workers = (0..options.workers.min(options.population))
+        .map(|_| Worker::start(options))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut population: Vec<Genome> = (0..options.population).map(|_| Genome::random(&mut rng)).collect();
+    let mut best: Option<(f32, Genome)> = None;
+
+    for generation in 1..=options.generations {
+        let fitness = evaluate_all(&mut workers, &population, options)?;
+        let mut ranked: Vec<(f32, Genome)> = fitness.into_iter().zip(population).collect();
+        ranked.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+        let mean = ranked.iter().map(|(fitness, _)| fitness).sum::<f32>() / ranked.len() as f32;
+        println!("generation {generation}: best {:.0}, mean {mean:.0}", ranked[0].0);
+        if best.as_ref().is_none_or(|(fitness, _)| ranked[0].0 > *fitness) {
+            best = Some(ranked[0].clone());
+            // saved as it improves, so stopping early keeps the best so far
+            ranked[0].1.save(&options.out).map_err(|err| format!("can't write {}: {err}", options.out.display()))?;
+        }
+
+        population = ranked.iter().take(ELITES).map(|(_, genome)| genome.clone()).collect();
+        while population.len() < options.population {
+            let mut child = tournament(&ranked, &mut rng).crossover(tournament(&ranked, &mut rng), &mut rng);
+            child.mutate(&mut rng, MUTATION_RATE, MUTATION_STRENGTH);
+            population.push(child);
+        }
+    }
+    if let Some((fitness, _)) = best {
+        println!("saved the best network, fitness {fitness:.0}, to {}", options.out.display());
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let options = match Options::from_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{err}\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match train(&options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -1,17 +1,53 @@
+use crate::assets::LayerKind;
+use bevy::color::Color;
 use bevy::math::{Vec2, Vec3};
 use bevy::prelude::{Component, Deref, DerefMut, Timer};
+pub use my_bevy_game::agent::ObstacleKind;
 
 #[derive(Component)]
 pub struct Player;
 
+#[derive(Component)]
+pub struct GameCamera;
+
+#[derive(Component)]
+pub struct Music;
+
+#[derive(Component)]
+pub struct SettingsMenu {
+    pub selected: usize,
+    // so closing it only resumes the game if it was running when it opened
+    pub was_paused: bool,
+}
+
+#[derive(Component)]
+pub struct SettingsMenuText;
+
 #[derive(Component)]
 pub struct Velocity(pub Vec3);
 
+// invulnerable while recovering from a hit
+#[derive(Component)]
+pub struct Hurt {
+    pub timer: Timer,
+    pub blink: Timer,
+}
+
 #[derive(Component)]
 pub struct CactusRoot;
 
 #[derive(Component)]
-pub struct HealthPickup;
+pub struct PowerUp(pub PowerUpKind);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PowerUpKind {
+    Health,
+    Shield,
+    Magnet,
+    SlowMo,
+    DoubleJump,
+    ScoreMultiplier,
+}
 
 #[derive(Component)]
 pub struct GameOverText;
@@ -19,6 +55,56 @@ pub struct GameOverText;
 #[derive(Component)]
 pub struct PauseText;
 
+// on-screen buttons for touch screens, see Settings::touch_buttons
+#[derive(Component, Clone, Copy, PartialEq)]
+pub enum TouchButton {
+    Jump,
+    Duck,
+    Pause,
+}
+
+// turns the on-screen buttons on or off from the pause screen, where touch players can reach it
+#[derive(Component)]
+pub struct TouchButtonsToggle;
+
+#[derive(Component)]
+pub struct LoadingScreen;
+
+// the title over the autopilot demo, until the player starts a run
+#[derive(Component)]
+pub struct MainMenu;
+
+// what a network playing with --genome sees and decides, filled in every frame
+#[derive(Component)]
+pub struct NetworkOverlay;
+
+#[derive(Component, Clone, Copy, PartialEq)]
+pub enum NetworkBar {
+    Input(usize),
+    Output(usize),
+}
+
+// the name of one of the network's actions, lit up while it's taken
+#[derive(Component)]
+pub struct NetworkActionLabel(pub usize);
+
+// lists every achievement and the lifetime totals, opened with A
+#[derive(Component)]
+pub struct AchievementsScreen {
+    // like the settings menu, the game only resumes on close if it was running
+    pub was_paused: bool,
+}
+
+// shown at the top of the screen when an achievement is unlocked, then fades out
+#[derive(Component)]
+pub struct Toast(pub Timer);
+
+#[derive(Component)]
+pub struct LoadingBar;
+
+#[derive(Component)]
+pub struct LoadingText;
+
 #[derive(Component)]
 pub struct Health(pub usize);
 
@@ -28,6 +114,9 @@ pub struct HealthInfo;
 #[derive(Component)]
 pub struct ScoreInfo;
 
+#[derive(Component)]
+pub struct PowerUpInfo;
+
 #[derive(Component)]
 pub struct AnimationIndices {
     pub first: usize,
@@ -42,6 +131,24 @@ pub struct Collider {
     pub size: Vec2,
 }
 
+// tags the collider of anything that hurts the player
+#[derive(Component)]
+pub struct Obstacle(pub ObstacleKind);
+
+// the player is inside the inflated zone around this obstacle collider
+#[derive(Component)]
+pub struct Graze {
+    pub closest: f32,
+    pub touched: bool,
+}
+
+// went past the dino's back untouched, and counted in the run's stats
+#[derive(Component)]
+pub struct Cleared;
+
+#[derive(Component)]
+pub struct Popup(pub Timer);
+
 #[derive(Component)]
 pub struct CactusArm;
 
@@ -60,8 +167,63 @@ pub struct PterodactylCollider;
 #[derive(Component)]
 pub struct PlayerCollider;
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ParticleKind {
+    Dust,
+    Sparks,
+    Feathers,
+    Crumbs,
+}
+
+#[derive(Component)]
+pub struct Particle {
+    pub velocity: Vec2,
+    pub spin: f32,
+    pub gravity: f32,
+    pub drag: f32,
+    pub scroll: f32,
+    pub shrink: bool,
+    pub size: Vec2,
+    pub lifetime: Timer,
+}
+
+// a background sprite spawned from a biome
+#[derive(Component)]
+pub struct BiomeLayer {
+    pub kind: LayerKind,
+    pub tint: Color,
+    // opacity while crossfading between biomes
+    pub fade: f32,
+    pub z: f32,
+    // index into the loaded biomes
+    pub biome: usize,
+}
+
 #[derive(Component)]
 pub struct Layer {
-    pub current_size_x: f32,
+    // how far the tiles have scrolled, always less than one image
+    pub offset: f64,
     pub speed_scale: f32,
-}
\ No newline at end of file
+}
+#[derive(Component)]
+pub struct Boulder {
+    pub radius: f32,
+}
+
+#[derive(Component)]
+pub struct BoulderRock;
+
+#[derive(Component)]
+pub struct BoulderCollider;
+
+#[derive(Component)]
+pub struct LavaPit;
+
+#[derive(Component)]
+pub struct LavaPitCollider;
+
+#[derive(Component)]
+pub struct MeteorShadow(pub Timer);
+
+#[derive(Component)]
+pub struct Meteor;
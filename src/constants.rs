@@ -3,6 +3,8 @@ use bevy::math::Vec2;
 pub const GROUND_LEVEL: f32 = -330.0;
 pub const GAME_SPEED: f32 = 500.0;
 pub const WINDOW_WIDTH: f32 = 1280.0;
+pub const WINDOW_HEIGHT: f32 = 720.0;
+pub const PLAYER_X: f32 = -300.0;
 
 pub const PTERO_SIZE_X: u32 = 862;
 pub const PTERO_SIZE_Y: u32 = 970;
@@ -0,0 +1,20 @@
+use bevy::asset::io::memory::{Dir, MemoryAssetReader};
+use bevy::asset::io::{AssetSource, AssetSourceId};
+use bevy::prelude::*;
+use std::path::Path;
+
+// generated by build.rs from static/assets
+include!(concat!(env!("OUT_DIR"), "/embedded_assets.rs"));
+
+// serves the assets compiled into the binary in place of the asset folder,
+// has to be called before the AssetPlugin is added
+pub fn use_embedded_assets(app: &mut App) {
+    let dir = Dir::default();
+    for (path, bytes) in ASSETS {
+        dir.insert_asset(Path::new(path), *bytes);
+    }
+    app.register_asset_source(
+        AssetSourceId::Default,
+        AssetSource::build().with_reader(move || Box::new(MemoryAssetReader { root: dir.clone() })),
+    );
+}
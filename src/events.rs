@@ -0,0 +1,74 @@
+use crate::components::{ObstacleKind, ParticleKind, PowerUpKind};
+use bevy::math::Vec2;
+use bevy::prelude::{Entity, Event};
+
+// the player touched an obstacle, entity is the obstacle's collider
+#[derive(Event)]
+pub struct ObstacleHit {
+    pub kind: ObstacleKind,
+    pub entity: Entity,
+}
+
+#[derive(Event)]
+pub struct PickupCollected {
+    pub kind: PowerUpKind,
+    pub entity: Entity,
+}
+
+#[derive(Event)]
+pub struct PlayerJumped;
+
+#[derive(Event)]
+pub struct PlayerLanded;
+
+#[derive(Event)]
+pub struct PlayerDucked;
+
+// what the player wants the dino to do, from the keyboard, touch screen or on-screen buttons
+#[derive(Event, Clone, Copy, PartialEq, Debug)]
+pub enum PlayerAction {
+    Jump,
+    StartDuck,
+    StopDuck,
+}
+
+// pause or resume, like pressing P
+#[derive(Event)]
+pub struct PauseRequested;
+
+// start a new run, from the game over screen or the host page
+#[derive(Event)]
+pub struct RestartGame;
+
+// the player lost health but is still alive
+#[derive(Event)]
+pub struct PlayerDamaged;
+
+// the player passed close to an obstacle without touching it
+#[derive(Event)]
+pub struct NearMiss {
+    pub kind: ObstacleKind,
+    // closest gap between the two colliders, in pixels
+    pub distance: f32,
+}
+
+// the score passed another multiple of 100
+#[derive(Event)]
+pub struct ScoreMilestone(pub u32);
+
+#[derive(Event)]
+pub struct PlayerDied {
+    pub cause: ObstacleKind,
+}
+
+#[derive(Event)]
+pub struct AchievementUnlocked {
+    pub name: String,
+    pub description: String,
+}
+
+#[derive(Event)]
+pub struct ParticleBurst {
+    pub kind: ParticleKind,
+    pub position: Vec2,
+}
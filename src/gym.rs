@@ -0,0 +1,226 @@
+use crate::build_app;
+use crate::headless::{FRAME, LOADING_TIMEOUT};
+use crate::options::GameOptions;
+use crate::resources::{Controller, ObstacleSpawningTimer, RealTimer, Score};
+use crate::states::GameState;
+use crate::events::RestartGame;
+use crate::systems::game::score::DISTANCE_PER_POINT;
+use bevy::app::PluginsState;
+use bevy::prelude::*;
+use bevy::tasks::tick_global_task_pools_on_main_thread;
+use bevy::time::TimeUpdateStrategy;
+use bevy_prng::WyRand;
+use bevy_rand::prelude::{Global, RngSeed, SeedSource};
+use my_bevy_game::agent::{AgentAction, FRAMES_PER_STEP, Observation, PlayerState, Step};
+use serde::{Deserialize, Serialize};
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::time::{Duration, Instant};
+
+// the game as a reinforcement learning environment. reset starts a run, step holds an action for a few
+// frames and reports what came of it. frames are simulated a sixtieth of a second at a time however long
+// they take, so the same seed and the same actions always play out the same way
+
+// in points, the same as the score, which goes up by 10 a second at the starting speed
+const DEATH_PENALTY: f32 = 100.0;
+// long enough to land from a jump or play the death animation before starting over
+const SETTLE_FRAMES: u32 = 600;
+
+pub struct Env {
+    app: App,
+    done: bool,
+}
+
+impl Env {
+    pub fn new(options: &GameOptions) -> Env {
+        let mut app = build_app(options);
+        app.insert_resource(Controller::Agent)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+        // what App::run does before the first frame
+        while app.plugins_state() == PluginsState::Adding {
+            tick_global_task_pools_on_main_thread();
+        }
+        app.finish();
+        app.cleanup();
+        Env { app, done: true }
+    }
+
+    // starts a new run, the same run every time for the same seed
+    pub fn reset(&mut self, seed: Option<u64>) -> Result<Step, String> {
+        self.wait_for_assets()?;
+        // let go of the buttons and let the dino land, or finish dying
+        *self.app.world_mut().resource_mut::<AgentAction>() = AgentAction::Noop;
+        for _ in 0..SETTLE_FRAMES {
+            let settled = match self.app.world().resource::<State<GameState>>().get() {
+                GameState::InGame => self.observation().player.state == PlayerState::Running,
+                _ => true,
+            };
+            if settled {
+                break;
+            }
+            self.app.update();
+        }
+
+        let world = self.app.world_mut();
+        if let Some(seed) = seed {
+            let global = world
+                .query_filtered::<Entity, With<Global>>()
+                .single(world)
+                .map_err(|err| format!("no random number generator to seed: {err}"))?;
+            world.entity_mut(global).insert(RngSeed::<WyRand>::from_seed(seed.to_le_bytes()));
+        }
+        world.send_event(RestartGame);
+        self.app.update();
+        // the restart may or may not have been a frame of the game, either way obstacles and speed-ups
+        // come at the same times from here
+        let world = self.app.world_mut();
+        world.resource_mut::<ObstacleSpawningTimer>().0.reset();
+        world.resource_mut::<RealTimer>().0.reset();
+
+        self.done = false;
+        Ok(Step {
+            observation: self.observation().clone(),
+            reward: 0.0,
+            done: false,
+        })
+    }
+
+    pub fn step(&mut self, action: AgentAction, frames: u32) -> Result<Step, String> {
+        if self.done {
+            return Err("the run is over, reset to start another".to_string());
+        }
+        *self.app.world_mut().resource_mut::<AgentAction>() = action;
+        let start = self.app.world().resource::<Score>().distance;
+        for _ in 0..frames {
+            self.app.update();
+            if self.observation().player.state == PlayerState::Dead {
+                self.done = true;
+                break;
+            }
+        }
+        let distance = self.app.world().resource::<Score>().distance - start;
+        let penalty = if self.done { DEATH_PENALTY } else { 0.0 };
+        Ok(Step {
+            observation: self.observation().clone(),
+            reward: distance / DISTANCE_PER_POINT - penalty,
+            done: self.done,
+        })
+    }
+
+    fn observation(&self) -> &Observation {
+        self.app.world().resource::<Observation>()
+    }
+
+    fn wait_for_assets(&mut self) -> Result<(), String> {
+        let started = Instant::now();
+        while *self.app.world().resource::<State<GameState>>().get() == GameState::Loading {
+            if started.elapsed() > LOADING_TIMEOUT {
+                return Err("the assets did not load".to_string());
+            }
+            self.app.update();
+            // leave the loading threads some time
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        Ok(())
+    }
+}
+
+// one JSON object per line, each answered with a step or an error
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(tag = "cmd", rename_all = "snake_case")]
+enum Request {
+    Reset {
+        #[serde(default)]
+        seed: Option<u64>,
+    },
+    Step {
+        action: AgentAction,
+        #[serde(default)]
+        frames: Option<u32>,
+    },
+    Close,
+}
+
+#[derive(Serialize)]
+struct Failure {
+    error: String,
+}
+
+pub fn serve(options: &GameOptions) -> Result<(), String> {
+    let mut env = Env::new(options);
+    match options.gym.as_deref() {
+        None | Some("stdio") => serve_lines(&mut env, io::stdin().lock(), io::stdout().lock()).map_err(|err| err.to_string()),
+        Some(addr) => {
+            let listener = TcpListener::bind(addr).map_err(|err| format!("can't listen on {addr}: {err}"))?;
+            info!("waiting for agents on {addr}");
+            // one agent at a time, each picking up the same game
+            for stream in listener.incoming() {
+                let served = stream.and_then(|stream| serve_lines(&mut env, BufReader::new(stream.try_clone()?), stream));
+                if let Err(err) = served {
+                    warn!("agent disconnected: {err}");
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+fn serve_lines(env: &mut Env, reader: impl BufRead, mut writer: impl Write) -> io::Result<()> {
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let reply = match serde_json::from_str(&line) {
+            Ok(Request::Reset { seed }) => env.reset(seed),
+            Ok(Request::Step { action, frames }) => env.step(action, frames.unwrap_or(FRAMES_PER_STEP)),
+            Ok(Request::Close) => return Ok(()),
+            Err(err) => Err(format!("bad request: {err}")),
+        };
+        let json = match reply {
+            Ok(step) => serde_json::to_string(&step),
+            Err(error) => serde_json::to_string(&Failure { error }),
+        }?;
+        writeln!(writer, "{json}")?;
+        writer.flush()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_requests() {
+        let parse = |line: &str| serde_json::from_str::<Request>(line).ok();
+        assert_eq!(parse(r#"{"cmd": "reset"}"#), Some(Request::Reset { seed: None }));
+        assert_eq!(parse(r#"{"cmd": "reset", "seed": 7}"#), Some(Request::Reset { seed: Some(7) }));
+        assert_eq!(parse(r#"{"cmd": "step", "action": "duck"}"#), Some(Request::Step { action: AgentAction::Duck, frames: None }));
+        assert_eq!(parse(r#"{"cmd": "step", "action": "jump", "frames": 1}"#), Some(Request::Step { action: AgentAction::Jump, frames: Some(1) }));
+        assert_eq!(parse(r#"{"cmd": "close"}"#), Some(Request::Close));
+        assert_eq!(parse(r#"{"cmd": "step", "action": "fly"}"#), None);
+    }
+
+    // the whole game, assets and all, played twice from the same seed
+    #[test]
+    fn replays_a_seed() {
+        let mut env = Env::new(&GameOptions { gym: Some("stdio".to_string()), ..default() });
+        let play = |env: &mut Env| {
+            let mut rewards = vec![];
+            env.reset(Some(7)).unwrap();
+            for i in 0..300 {
+                let action = if i % 10 == 0 { AgentAction::Jump } else { AgentAction::Noop };
+                let step = env.step(action, FRAMES_PER_STEP).unwrap();
+                rewards.push((step.reward, step.observation.obstacles.first().map(|obstacle| obstacle.x)));
+                if step.done {
+                    break;
+                }
+            }
+            rewards
+        };
+        let first = play(&mut env);
+        assert!(first.iter().any(|(_, obstacle)| obstacle.is_some()));
+        assert_eq!(first, play(&mut env));
+    }
+}
@@ -0,0 +1,82 @@
+use crate::events::PlayerDied;
+use crate::resources::{Controller, Score};
+use crate::states::GameState;
+use bevy::app::{PluginGroupBuilder, ScheduleRunnerPlugin};
+use bevy::prelude::*;
+use bevy::render::settings::WgpuSettings;
+use bevy::render::RenderPlugin;
+use bevy::time::TimeUpdateStrategy;
+use bevy::window::ExitCondition;
+use bevy::winit::WinitPlugin;
+use std::time::{Duration, Instant};
+
+// the game without a window or graphics, stepping frames as fast as it can with the autopilot playing,
+// to check in CI that the game can still be played through
+
+pub const FRAME: Duration = Duration::from_nanos(1_000_000_000 / 60);
+// gives up if the assets haven't loaded by then, in wall clock time
+pub const LOADING_TIMEOUT: Duration = Duration::from_secs(300);
+
+#[derive(Resource)]
+struct HeadlessRun {
+    secs: f32,
+}
+
+pub fn without_window(plugins: PluginGroupBuilder) -> PluginGroupBuilder {
+    plugins
+        .set(WindowPlugin {
+            primary_window: None,
+            exit_condition: ExitCondition::DontExit,
+            ..default()
+        })
+        .disable::<WinitPlugin>()
+        .set(RenderPlugin {
+            render_creation: WgpuSettings { backends: None, ..default() }.into(),
+            ..default()
+        })
+}
+
+pub fn add_headless_systems(app: &mut App, secs: f32) {
+    app.add_plugins(ScheduleRunnerPlugin::run_loop(Duration::ZERO))
+        // every frame is a sixtieth of a second, however long it took to simulate
+        .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME))
+        .insert_resource(HeadlessRun { secs })
+        .add_systems(Update, finish_headless_run);
+}
+
+// passes once the autopilot, or the network given with --genome, has survived long enough, fails if it dies first
+#[allow(clippy::too_many_arguments)]
+fn finish_headless_run(
+    run: Res<HeadlessRun>,
+    controller: Res<Controller>,
+    time: Res<Time<Real>>,
+    state: Res<State<GameState>>,
+    score: Res<Score>,
+    mut died: EventReader<PlayerDied>,
+    mut started: Local<Option<Instant>>,
+    mut survived: Local<f32>,
+    mut exit: EventWriter<AppExit>,
+) {
+    match state.get() {
+        GameState::Loading => {
+            if started.get_or_insert_with(Instant::now).elapsed() > LOADING_TIMEOUT {
+                error!("the assets did not load");
+                exit.write(AppExit::from_code(2));
+            }
+        }
+        GameState::InGame => {
+            let player = if *controller == Controller::Network { "the network" } else { "the autopilot" };
+            if let Some(died) = died.read().last() {
+                error!("{player} was killed by {:?} after {:.1}s, score {}", died.cause, *survived, score.total());
+                exit.write(AppExit::from_code(1));
+                return;
+            }
+            *survived += time.delta_secs();
+            if *survived >= run.secs {
+                info!("{player} survived {:.0}s, score {}", run.secs, score.total());
+                exit.write(AppExit::Success);
+            }
+        }
+        GameState::GameOver => {}
+    }
+}
@@ -0,0 +1,4 @@
+// code shared between the game and its command line tools
+pub mod agent;
+pub mod neural;
+pub mod synth;
@@ -1,127 +1,399 @@
+mod assets;
 mod components;
 mod constants;
+#[cfg(feature = "embedded-assets")]
+mod embedded;
+mod events;
+#[cfg(not(target_arch = "wasm32"))]
+mod gym;
+#[cfg(not(target_arch = "wasm32"))]
+mod headless;
+mod options;
 mod resources;
 mod states;
+mod storage;
+#[cfg(target_arch = "wasm32")]
+mod web;
 mod systems {
+    pub mod audio;
     pub mod background;
+    pub mod day_night;
+    pub mod particles;
     pub mod game {
+        pub mod achievements;
+        pub mod camera_effects;
         pub mod end;
+        pub mod loading;
+        pub mod main_menu;
+        pub mod network_overlay;
         pub mod pause;
+        pub mod popup;
+        pub mod score;
+        pub mod settings_menu;
+        pub mod stats;
         pub mod setup;
+        pub mod viewport;
     }
     pub mod player {
+        pub mod agent;
         pub mod animation;
+        pub mod autopilot;
         pub mod health;
+        pub mod hurt;
+        pub mod input;
         pub mod movement;
+        pub mod power_ups;
     }
     pub mod obstacles {
+        pub mod boulder;
         pub mod cactus;
         pub mod collision;
+        pub mod hit;
+        pub mod lava;
+        pub mod meteor;
         pub mod movement;
+        pub mod near_miss;
+        pub mod pickup;
     }
 }
 
-use crate::constants::WINDOW_WIDTH;
-use crate::resources::{ObstacleSpawningTimer, RealTimer, ScoreOffset};
-use crate::states::GameState::{GameOver, InGame};
-use crate::systems::background::{initialize_background, scroll_background};
-use crate::systems::game::end::{game_over, restart_game};
-use crate::systems::game::pause::toggle_pause;
+use crate::assets::{AchievementList, Biome, EmitterConfig, RonAssetLoader};
+use crate::constants::{WINDOW_HEIGHT, WINDOW_WIDTH};
+use crate::components::{AchievementsScreen, MainMenu, SettingsMenu};
+use crate::events::{AchievementUnlocked, NearMiss, ObstacleHit, ParticleBurst, PickupCollected, PlayerDamaged, PlayerDied, PlayerDucked, PauseRequested, PlayerAction, PlayerJumped, PlayerLanded, RestartGame, ScoreMilestone};
+use crate::options::GameOptions;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::options::USAGE;
+use crate::resources::{ActivePowerUps, Brain, BaseSpeed, CameraEffects, Controller, HurtConfig, NearMissFlash, ObstacleSpawningTimer, Profile, RealTimer, RunStats, Score, Settings, VisibleArea};
+use crate::states::GameState::{GameOver, InGame, Loading};
+use crate::systems::audio::{load_audio, play_sound_effects, update_music};
+use crate::systems::background::{advance_biome, crossfade_biome_layers, initialize_background, scroll_background, spawn_biome_layers};
+use crate::systems::day_night::cycle_day_night;
+use crate::systems::particles::{burst_on_hits, emit_particles, load_particle_emitters, update_particles};
+use crate::systems::game::achievements::{check_achievements, count_knockdowns, load_achievements, record_run, show_achievement_toasts, toggle_achievements_screen, update_toasts};
+use crate::systems::game::camera_effects::{apply_camera_effects, toggle_camera_effects, trigger_camera_effects};
+use crate::systems::game::end::{game_over, restart_game, restart_on_input};
+use crate::systems::game::loading::{spawn_loading_screen, update_loading};
+use crate::systems::game::main_menu::{restart_demo, spawn_main_menu, start_from_main_menu};
+use crate::systems::game::network_overlay::{outline_observed_obstacles, spawn_network_overlay, update_network_overlay};
+use crate::systems::game::pause::{toggle_pause, toggle_touch_buttons};
+use crate::systems::game::popup::update_popups;
+use crate::systems::game::score::{award_bonuses, render_score_info, start_score_flash, update_distance_score};
+use crate::systems::game::settings_menu::{navigate_settings_menu, render_settings_menu, toggle_settings_menu};
 use crate::systems::game::setup::setup;
+use crate::systems::game::stats::{count_cleared_obstacles, track_run_stats};
+use crate::systems::game::viewport::{fit_viewport, toggle_fullscreen};
+use crate::systems::obstacles::boulder::roll_boulders;
 #[allow(unused_imports)]
 use crate::systems::obstacles::collision::{debug_outlines, detect_collision};
+use crate::systems::obstacles::hit::{bounce_boulder, despawn_hit_colliders, knock_down_pterodactyl, knock_off_cactus_arms};
+use crate::systems::obstacles::meteor::fall_meteors;
+use crate::systems::obstacles::near_miss::{detect_near_misses, near_miss_feedback, tick_near_miss_flash};
 use crate::systems::obstacles::movement::{
     drop_obstacles, move_ground_obstacles, move_sky_obstacles, spawn_obstacles,
 };
-use crate::systems::player::health::{check_health, render_health_info, render_score_info};
+use crate::systems::player::agent::{agent_actions, drive_network, observe};
+use crate::systems::player::autopilot::drive_autopilot;
+use crate::systems::player::health::{apply_obstacle_damage, check_health, render_health_info};
+use crate::systems::player::hurt::{start_hurt, update_hurt};
+use crate::systems::player::input::{keyboard_actions, sync_touch_buttons, touch_button_actions, touch_gestures};
 use crate::systems::player::movement::{animate_sprite, apply_gravity, change_time_speed, duck, drop_player, jump};
+use crate::systems::player::power_ups::{apply_pickups, attract_pickups, render_power_up_info, tick_power_ups, tint_shielded_player};
 
 use bevy::asset::AssetMetaCheck;
+use bevy::audio::AddAudioSource;
 use bevy::input::common_conditions::input_just_pressed;
 use bevy::prelude::*;
 use bevy_prng::WyRand;
 use bevy_rand::prelude::EntropyPlugin;
+use my_bevy_game::agent::{AgentAction, Observation};
+use my_bevy_game::synth::SynthSound;
 
 #[cfg(debug_assertions)] // Development mode
 const SPAWN_INTERVAL: f32 = 1.5;
-#[cfg(debug_assertions)]
-const ASSET_FOLDER: &str = "static/assets";
 
 #[cfg(not(debug_assertions))] // Release mode
 const SPAWN_INTERVAL: f32 = 1.5;
-#[cfg(not(debug_assertions))]
-const ASSET_FOLDER: &str = "assets";
 
 
-#[cfg(target_arch = "wasm32")]
-use wasm_bindgen::prelude::*;
+fn main() {
+    // on the web the host page starts the game through web::start
+    #[cfg(not(target_arch = "wasm32"))]
+    match GameOptions::from_args(std::env::args().skip(1)) {
+        Ok(options) if options.gym.is_some() => {
+            if let Err(err) = gym::serve(&options) {
+                eprintln!("{err}");
+                std::process::exit(1);
+            }
+        }
+        Ok(options) => {
+            if let AppExit::Error(code) = run(options) {
+                std::process::exit(code.get().into());
+            }
+        }
+        Err(err) => {
+            eprintln!("{err}\n{USAGE}");
+            std::process::exit(2);
+        }
+    }
+}
 
-#[cfg_attr(target_arch = "wasm32", wasm_bindgen(start))]
-pub fn run() {
+pub fn run(options: GameOptions) -> AppExit {
+    let mut app = build_app(&options);
     #[cfg(target_arch = "wasm32")]
-    console_error_panic_hook::set_once();
-
-    main();
+    web::add_host_systems(&mut app);
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(secs) = options.headless {
+        headless::add_headless_systems(&mut app, secs);
+    }
+    app.run()
 }
 
-fn main() {
+// the whole game, leaving it to the caller how frames are run
+pub fn build_app(options: &GameOptions) -> App {
     let primary_window = Window {
         title: "Dino Runner".to_string(),
-        resolution: (WINDOW_WIDTH, 720.0).into(),
-        resizable: false,
+        resolution: (WINDOW_WIDTH, WINDOW_HEIGHT).into(),
+        canvas: options.canvas_id.as_ref().map(|id| format!("#{id}")),
+        // the canvas fills the page on the web
+        fit_canvas_to_parent: true,
         ..default()
     };
+    let entropy = match options.seed {
+        Some(seed) => EntropyPlugin::<WyRand>::with_seed(seed.to_le_bytes()),
+        None => EntropyPlugin::<WyRand>::default(),
+    };
     let mut binding = App::new();
-    let mut app = binding
-        .add_plugins(EntropyPlugin::<WyRand>::default())
-        .add_plugins(
-            DefaultPlugins
-                .set(WindowPlugin {
-                    primary_window: Some(primary_window),
-                    ..default()
-                })
-                .set(ImagePlugin::default_nearest())
-                .set(AssetPlugin {
-                    file_path: ASSET_FOLDER.to_string(),
-                    meta_check: AssetMetaCheck::Never,
-                    ..default()
-                }),
-        )
+    // an explicit asset folder wins over the embedded assets, to try out changes without rebuilding
+    #[cfg(feature = "embedded-assets")]
+    if options.asset_root.is_none() {
+        embedded::use_embedded_assets(&mut binding);
+    }
+    let plugins = DefaultPlugins
+        .set(WindowPlugin {
+            primary_window: Some(primary_window),
+            ..default()
+        })
+        .set(ImagePlugin::default_nearest())
+        .set(AssetPlugin {
+            file_path: options.asset_root(),
+            meta_check: AssetMetaCheck::Never,
+            ..default()
+        });
+    #[cfg(not(target_arch = "wasm32"))]
+    let plugins = if options.windowless() { headless::without_window(plugins) } else { plugins };
+    let app = binding
+        .add_plugins(entropy)
+        .add_plugins(plugins)
         .insert_resource(ObstacleSpawningTimer(Timer::from_seconds(
             SPAWN_INTERVAL,
             TimerMode::Repeating,
         )))
         .insert_resource(RealTimer(Timer::from_seconds(SPAWN_INTERVAL, TimerMode::Repeating)))
-        .insert_resource(ScoreOffset(0.0))
-        .insert_state(InGame)
-        .add_systems(Startup, (setup, initialize_background))
+        .init_resource::<Score>()
+        .init_resource::<RunStats>()
+        .insert_resource(options.difficulty)
+        .insert_resource(BaseSpeed(options.difficulty.start_speed()))
+        // the autopilot plays until someone starts a run from the main menu
+        .insert_resource(Controller::Autopilot)
+        .init_resource::<AgentAction>()
+        .init_resource::<Observation>()
+        .init_resource::<ActivePowerUps>()
+        .init_resource::<HurtConfig>()
+        .init_resource::<NearMissFlash>()
+        .init_resource::<VisibleArea>()
+        .init_resource::<CameraEffects>()
+        .insert_resource(Settings::load())
+        .insert_resource(Profile::load())
+        .add_audio_source::<SynthSound>()
+        .init_asset::<Biome>()
+        .register_asset_loader(RonAssetLoader::<Biome>::new(&["biome.ron"]))
+        .init_asset::<EmitterConfig>()
+        .register_asset_loader(RonAssetLoader::<EmitterConfig>::new(&["emitter.ron"]))
+        .init_asset::<AchievementList>()
+        .register_asset_loader(RonAssetLoader::<AchievementList>::new(&["achievements.ron"]))
+        .add_event::<ObstacleHit>()
+        .add_event::<PickupCollected>()
+        .add_event::<PlayerDamaged>()
+        .add_event::<PlayerDied>()
+        .add_event::<ScoreMilestone>()
+        .add_event::<NearMiss>()
+        .add_event::<ParticleBurst>()
+        .add_event::<AchievementUnlocked>()
+        .add_event::<PlayerJumped>()
+        .add_event::<PlayerLanded>()
+        .add_event::<PlayerDucked>()
+        .add_event::<RestartGame>()
+        .add_event::<PlayerAction>()
+        .add_event::<PauseRequested>()
+        .insert_state(Loading)
+        .add_systems(Startup, (setup, initialize_background, load_particle_emitters, load_audio, load_achievements))
         .add_systems(
             Update,
             (
-                spawn_obstacles,
-                move_ground_obstacles,
-                move_sky_obstacles,
-                drop_obstacles,
-                detect_collision,
-                render_health_info,
-                check_health,
-                animate_sprite,
-                jump,
-                apply_gravity,
-                drop_player,
-                duck,
-                scroll_background,
-                toggle_pause.run_if(input_just_pressed(KeyCode::KeyP)),
-                change_time_speed,
-                render_score_info,
+                (
+                    spawn_obstacles,
+                    move_ground_obstacles,
+                    move_sky_obstacles,
+                    roll_boulders,
+                    fall_meteors,
+                    drop_obstacles,
+                    (
+                        detect_collision,
+                        (
+                            apply_obstacle_damage,
+                            apply_pickups,
+                            knock_off_cactus_arms,
+                            knock_down_pterodactyl,
+                            bounce_boulder,
+                            award_bonuses,
+                            burst_on_hits,
+                        ),
+                        despawn_hit_colliders,
+                    )
+                        .chain(),
+                    start_hurt.after(apply_obstacle_damage),
+                    detect_near_misses.after(despawn_hit_colliders),
+                    count_cleared_obstacles.after(detect_collision),
+                    near_miss_feedback.after(detect_near_misses),
+                    tick_near_miss_flash,
+                ),
+                (
+                    check_health.after(apply_obstacle_damage),
+                    animate_sprite,
+                    (keyboard_actions, touch_gestures, touch_button_actions)
+                        .run_if(resource_equals(Controller::Human))
+                        .before(jump)
+                        .before(duck),
+                    // after the meteors' fall has been timed this frame, which it reads back to where their colliders are
+                    drive_autopilot
+                        .run_if(resource_equals(Controller::Autopilot))
+                        .after(fall_meteors)
+                        .before(jump)
+                        .before(duck),
+                    drive_network.run_if(resource_equals(Controller::Network)).before(agent_actions),
+                    agent_actions
+                        .run_if(resource_equals(Controller::Agent).or(resource_equals(Controller::Network)))
+                        .before(jump)
+                        .before(duck),
+                    jump,
+                    apply_gravity,
+                    drop_player,
+                    duck,
+                    change_time_speed,
+                    tick_power_ups,
+                    attract_pickups,
+                    tint_shielded_player,
+                    update_hurt.after(tint_shielded_player),
+                ),
+                (
+                    update_distance_score,
+                    track_run_stats.after(check_health),
+                    start_score_flash.after(update_distance_score),
+                    render_health_info,
+                    render_score_info.after(start_score_flash),
+                    render_power_up_info,
+                    update_popups,
+                    emit_particles.after(burst_on_hits).after(drop_player),
+                    update_particles.after(emit_particles),
+                    scroll_background,
+                    (advance_biome, spawn_biome_layers, crossfade_biome_layers, cycle_day_night).chain(),
+                    toggle_pause.run_if(input_just_pressed(KeyCode::KeyP).or(on_event::<PauseRequested>)),
+                    toggle_touch_buttons,
+                ),
             )
-                .run_if(in_state(InGame)),
+                .run_if(in_state(InGame).and(no_menu_open)),
+        )
+        // once everything has moved, for an agent to decide on between frames
+        .add_systems(
+            PostUpdate,
+            observe
+                .after(TransformSystem::TransformPropagate)
+                .run_if(resource_equals(Controller::Agent).or(resource_equals(Controller::Network))),
         )
+        .add_systems(OnEnter(Loading), spawn_loading_screen)
+        .add_systems(Update, update_loading.run_if(in_state(Loading)))
         .add_systems(OnEnter(GameOver), game_over)
-        .add_systems(Update, restart_game.run_if(in_state(GameOver)));
+        // only the player's own runs count towards the profile
+        .add_systems(OnEnter(GameOver), record_run.run_if(resource_equals(Controller::Human)))
+        .add_systems(
+            Update,
+            (
+                count_knockdowns.run_if(in_state(InGame)),
+                check_achievements.run_if(not(in_state(Loading))),
+            )
+                .run_if(resource_equals(Controller::Human)),
+        )
+        .add_systems(
+            Update,
+            (
+                (show_achievement_toasts, update_toasts).chain(),
+                // after the settings menu has seen the screen still open, so Esc only closes this
+                toggle_achievements_screen.after(render_settings_menu).run_if(
+                    not(in_state(Loading))
+                        .and(not(any_with_component::<SettingsMenu>))
+                        .and(input_just_pressed(KeyCode::KeyA).or(input_just_pressed(KeyCode::Escape).and(any_with_component::<AchievementsScreen>))),
+                ),
+            ),
+        )
+        .add_systems(
+            Update,
+            (
+                start_from_main_menu.before(restart_game).run_if(no_menu_open),
+                restart_demo.before(restart_game).run_if(in_state(GameOver)),
+            )
+                .run_if(any_with_component::<MainMenu>),
+        )
+        .add_systems(Update, restart_on_input.run_if(in_state(GameOver).and(no_menu_open)))
+        .add_systems(Update, restart_game.after(restart_on_input).run_if(not(in_state(Loading))))
+        .add_systems(
+            Update,
+            (
+                fit_viewport,
+                sync_touch_buttons,
+                toggle_fullscreen.run_if(input_just_pressed(KeyCode::KeyF)),
+                trigger_camera_effects.after(apply_obstacle_damage),
+                apply_camera_effects.after(trigger_camera_effects),
+                toggle_camera_effects.run_if(input_just_pressed(KeyCode::KeyE)),
+                play_sound_effects,
+                update_music,
+                (
+                    toggle_settings_menu.run_if(input_just_pressed(KeyCode::Escape).and(not(any_with_component::<AchievementsScreen>))),
+                    navigate_settings_menu,
+                    render_settings_menu,
+                )
+                    .chain(),
+            ),
+        );
+
+    if let Some(genome) = &options.genome {
+        // the network plays run after run, showing what it makes of each frame
+        app.insert_resource(Brain(genome.clone()))
+            .insert_resource(Controller::Network)
+            .add_systems(Startup, spawn_network_overlay)
+            .add_systems(
+                Update,
+                (
+                    (update_network_overlay, outline_observed_obstacles).run_if(in_state(InGame)),
+                    restart_demo.before(restart_game).run_if(in_state(GameOver)),
+                ),
+            );
+    } else if !options.autopilot && !options.windowless() {
+        app.add_systems(Startup, spawn_main_menu);
+    }
+    // the demo behind the main menu and runs nobody watched aren't worth keeping
+    #[cfg(not(target_arch = "wasm32"))]
+    if !options.windowless() {
+        app.add_systems(OnEnter(GameOver), systems::game::stats::log_run.run_if(not(any_with_component::<MainMenu>)));
+    }
+    setup_debug_systems(app);
+    binding
+}
 
-    setup_debug_systems(&mut app);
-    app.run();
+// the settings and achievements screens hold up the game and take over the keys while they're open
+#[allow(clippy::type_complexity)]
+pub fn no_menu_open(menus: Query<(), Or<(With<SettingsMenu>, With<AchievementsScreen>)>>) -> bool {
+    menus.is_empty()
 }
 
 fn setup_debug_systems(app: &mut App) -> &mut App {
@@ -0,0 +1,175 @@
+use crate::agent::{AgentAction, Observation, PlayerState, OBSERVED_OBSTACLES};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::{fs, io};
+
+// small feed-forward networks that play the game, evolved by the trainer binary
+
+// the dino's height, velocity and whether it's jumping or ducking, then for each obstacle
+// whether there is one, its position, size and speed
+pub const INPUTS: usize = 4 + OBSERVED_OBSTACLES * OBSTACLE_INPUTS;
+const OBSTACLE_INPUTS: usize = 6;
+const HIDDEN: usize = 8;
+// the strongest output is the action
+pub const ACTIONS: [AgentAction; 3] = [AgentAction::Noop, AgentAction::Jump, AgentAction::Duck];
+
+// about the largest each input gets, in pixels and pixels per second, so the network sees numbers around -1 to 1
+const HEIGHT_SCALE: f32 = 300.0;
+const VELOCITY_SCALE: f32 = 2000.0;
+const DISTANCE_SCALE: f32 = 1000.0;
+const SIZE_SCALE: f32 = 100.0;
+const SPEED_SCALE: f32 = 1000.0;
+
+pub fn input_names() -> Vec<String> {
+    let mut names: Vec<String> = ["height", "velocity", "jumping", "ducking"].map(String::from).to_vec();
+    for i in 1..=OBSERVED_OBSTACLES {
+        names.extend(["there", "x", "y", "width", "height", "speed"].map(|input| format!("#{i} {input}")));
+    }
+    names
+}
+
+pub fn inputs(observation: &Observation) -> [f32; INPUTS] {
+    let player = observation.player;
+    let flag = |state| if player.state == state { 1.0 } else { 0.0 };
+    let mut inputs = [0.0; INPUTS];
+    inputs[..4].copy_from_slice(&[
+        player.y / HEIGHT_SCALE,
+        player.velocity / VELOCITY_SCALE,
+        flag(PlayerState::Jumping),
+        flag(PlayerState::Ducking),
+    ]);
+    for (i, slot) in inputs[4..].chunks_mut(OBSTACLE_INPUTS).enumerate() {
+        slot.copy_from_slice(&match observation.obstacles.get(i) {
+            Some(obstacle) => [
+                1.0,
+                obstacle.x / DISTANCE_SCALE,
+                obstacle.y / HEIGHT_SCALE,
+                obstacle.width / SIZE_SCALE,
+                obstacle.height / SIZE_SCALE,
+                obstacle.speed / SPEED_SCALE,
+            ],
+            // nothing there, as good as far away
+            None => [0.0, 1.0, 0.0, 0.0, 0.0, 0.0],
+        });
+    }
+    inputs
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Genome {
+    // neurons in each layer, from the inputs to the outputs
+    pub layers: Vec<usize>,
+    // layer by layer, each neuron's bias followed by its weights
+    pub weights: Vec<f32>,
+}
+
+impl Genome {
+    pub fn random(rng: &mut impl Rng) -> Genome {
+        let layers = vec![INPUTS, HIDDEN, ACTIONS.len()];
+        let count = weight_count(&layers);
+        Genome {
+            layers,
+            weights: (0..count).map(|_| rng.gen_range(-1.0..1.0)).collect(),
+        }
+    }
+
+    // the activations of the output layer
+    pub fn outputs(&self, inputs: &[f32]) -> Vec<f32> {
+        let mut values = inputs.to_vec();
+        let mut weights = self.weights.iter();
+        for &size in &self.layers[1..] {
+            values = (0..size)
+                .map(|_| {
+                    let bias = weights.next().copied().unwrap_or_default();
+                    values
+                        .iter()
+                        .fold(bias, |sum, value| sum + value * weights.next().copied().unwrap_or_default())
+                        .tanh()
+                })
+                .collect();
+        }
+        values
+    }
+
+    pub fn decide(&self, observation: &Observation) -> AgentAction {
+        let outputs = self.outputs(&inputs(observation));
+        let strongest = (0..ACTIONS.len()).max_by(|&a, &b| outputs[a].total_cmp(&outputs[b])).unwrap_or_default();
+        ACTIONS[strongest]
+    }
+
+    // nudges each weight with the given chance, by up to strength either way
+    pub fn mutate(&mut self, rng: &mut impl Rng, rate: f32, strength: f32) {
+        for weight in &mut self.weights {
+            if rng.r#gen::<f32>() < rate {
+                *weight += rng.gen_range(-strength..strength);
+            }
+        }
+    }
+
+    // each weight from one parent or the other
+    pub fn crossover(&self, other: &Genome, rng: &mut impl Rng) -> Genome {
+        Genome {
+            layers: self.layers.clone(),
+            weights: self
+                .weights
+                .iter()
+                .zip(&other.weights)
+                .map(|(&mine, &theirs)| if rng.gen_bool(0.5) { mine } else { theirs })
+                .collect(),
+        }
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Genome, String> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path).map_err(|err| format!("can't read {}: {err}", path.display()))?;
+        let genome: Genome = serde_json::from_str(&json).map_err(|err| format!("{} is not a genome: {err}", path.display()))?;
+        let fits = genome.layers.first() == Some(&INPUTS)
+            && genome.layers.last() == Some(&ACTIONS.len())
+            && genome.weights.len() == weight_count(&genome.layers);
+        if !fits {
+            return Err(format!("{} was trained for different inputs or outputs", path.display()));
+        }
+        Ok(genome)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, serde_json::to_string(self)?)
+    }
+}
+
+fn weight_count(layers: &[usize]) -> usize {
+    layers.windows(2).map(|pair| (pair[0] + 1) * pair[1]).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn missing_obstacles_look_far_away() {
+        let inputs = inputs(&Observation::default());
+        assert_eq!(inputs.len(), input_names().len());
+        for slot in inputs[4..].chunks(OBSTACLE_INPUTS) {
+            assert_eq!(slot, [0.0, 1.0, 0.0, 0.0, 0.0, 0.0]);
+        }
+    }
+
+    #[test]
+    fn children_take_after_their_parents() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let (mother, father) = (Genome::random(&mut rng), Genome::random(&mut rng));
+        let mut child = mother.crossover(&father, &mut rng);
+        for (i, weight) in child.weights.iter().enumerate() {
+            assert!(*weight == mother.weights[i] || *weight == father.weights[i]);
+        }
+        let before = child.clone();
+        child.mutate(&mut rng, 0.0, 1.0);
+        assert_eq!(child, before);
+        child.mutate(&mut rng, 1.0, 0.1);
+        assert_ne!(child, before);
+        assert_eq!(child.outputs(&inputs(&Observation::default())).len(), ACTIONS.len());
+    }
+}
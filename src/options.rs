@@ -0,0 +1,89 @@
+use crate::resources::Difficulty;
+use my_bevy_game::neural::Genome;
+use serde::Deserialize;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub const USAGE: &str = "usage: my_bevy_game [--assets DIR] [--seed N] [--difficulty easy|normal|hard] [--autopilot] [--headless SECS] [--gym stdio|ADDR] [--genome FILE]
+  --assets DIR     load assets from DIR, also read from the DINO_ASSETS environment variable
+  --autopilot      let the built-in autopilot play instead of showing the main menu
+  --headless SECS  run the autopilot, or the --genome network, without a window, failing if it dies within SECS seconds
+  --gym stdio|ADDR let an agent play without a window, through JSON lines on stdin and stdout or a TCP address
+  --genome FILE    let a network saved by the trainer play, showing what it sees and decides";
+
+// how the game is started, from the command line natively and by the host page on the web
+#[derive(Deserialize, Default, Clone, Debug)]
+#[serde(default, rename_all = "camelCase")]
+pub struct GameOptions {
+    // generates the same sequence of obstacles and cacti every time
+    pub seed: Option<u64>,
+    pub difficulty: Difficulty,
+    // id of an existing canvas element to draw into, instead of adding one to the page
+    pub canvas_id: Option<String>,
+    // a directory natively, or a URL on the web when assets aren't served next to the page
+    #[serde(rename = "assetBaseUrl")]
+    pub asset_root: Option<String>,
+    // skips the main menu and leaves the autopilot playing
+    pub autopilot: bool,
+    // seconds the autopilot has to survive without a window, only from the command line
+    #[serde(skip)]
+    pub headless: Option<f32>,
+    // where a reinforcement learning agent connects, "stdio" or an address to listen on
+    #[serde(skip)]
+    pub gym: Option<String>,
+    // a network saved by the trainer, which plays instead of the autopilot
+    #[serde(skip)]
+    pub genome: Option<Genome>,
+}
+
+impl GameOptions {
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_args(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = GameOptions {
+            asset_root: std::env::var("DINO_ASSETS").ok(),
+            ..GameOptions::default()
+        };
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("{arg} needs a value"));
+            match arg.as_str() {
+                "--assets" => options.asset_root = Some(value()?),
+                "--autopilot" => options.autopilot = true,
+                "--genome" => options.genome = Some(Genome::load(value()?)?),
+                "--gym" => options.gym = Some(value()?),
+                "--headless" => options.headless = Some(value()?.parse().map_err(|_| "--headless needs a number of seconds")?),
+                "--seed" => options.seed = Some(value()?.parse().map_err(|_| "--seed needs a whole number")?),
+                "--difficulty" => {
+                    options.difficulty = match value()?.as_str() {
+                        "easy" => Difficulty::Easy,
+                        "normal" => Difficulty::Normal,
+                        "hard" => Difficulty::Hard,
+                        other => return Err(format!("unknown difficulty {other}")),
+                    }
+                }
+                _ => return Err(format!("unknown argument {arg}")),
+            }
+        }
+        // bevy resolves relative paths from the executable, but people expect them to be relative to where they are
+        if let Some(root) = &options.asset_root
+            && let Ok(cwd) = std::env::current_dir()
+        {
+            options.asset_root = Some(cwd.join(root).to_string_lossy().into_owned());
+        }
+        Ok(options)
+    }
+
+    // nobody is watching, the game only has to be simulated
+    pub fn windowless(&self) -> bool {
+        self.headless.is_some() || self.gym.is_some()
+    }
+
+    // the asset folder next to the executable, or in the source tree when started with cargo
+    pub fn asset_root(&self) -> String {
+        if let Some(root) = &self.asset_root {
+            root.clone()
+        } else if std::env::var_os("CARGO_MANIFEST_DIR").is_some() {
+            "static/assets".to_string()
+        } else {
+            "assets".to_string()
+        }
+    }
+}
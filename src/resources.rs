@@ -1,6 +1,14 @@
 use bevy::asset::Handle;
 use bevy::image::Image;
-use bevy::prelude::{Resource, Timer};
+use crate::assets::{AchievementList, Biome, EmitterConfig};
+use crate::components::{ObstacleKind, PowerUpKind};
+use crate::constants::{WINDOW_HEIGHT, WINDOW_WIDTH};
+use my_bevy_game::neural::Genome;
+use my_bevy_game::synth::SynthSound;
+use bevy::math::Vec2;
+use bevy::prelude::{Resource, Timer, TimerMode};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 
 #[derive(Resource)]
 pub struct ObstacleSpawningTimer(pub Timer);
@@ -9,7 +17,200 @@ pub struct ObstacleSpawningTimer(pub Timer);
 pub struct RealTimer(pub Timer);
 
 #[derive(Resource)]
-pub struct ScoreOffset(pub f32);
+pub struct Score {
+    // pixels travelled at the current game speed
+    pub distance: f32,
+    pub points: f32,
+    pub combo: u32,
+    pub combo_timer: Timer,
+    pub last_milestone: u32,
+    pub flash_points: u32,
+    pub flash: Timer,
+}
+
+impl Default for Score {
+    fn default() -> Self {
+        let mut flash = Timer::from_seconds(1.0, TimerMode::Once);
+        // nothing to flash before the first milestone
+        flash.tick(flash.duration());
+        Score {
+            distance: 0.0,
+            points: 0.0,
+            combo: 0,
+            combo_timer: Timer::from_seconds(3.0, TimerMode::Once),
+            last_milestone: 0,
+            flash_points: 0,
+            flash,
+        }
+    }
+}
+
+// what happened in the current run, shown on the game over screen and logged when it ends
+#[derive(Resource, Serialize, Default, Clone, Debug)]
+pub struct RunStats {
+    // seconds of play up to the dino's death, not counting pauses
+    pub duration: f32,
+    // the fastest the game went, as a multiple of the starting speed
+    pub max_speed: f32,
+    pub jumps: u32,
+    pub ducks: u32,
+    // obstacles that went past without touching the dino
+    pub cleared: BTreeMap<ObstacleKind, u32>,
+    // obstacles run into, including the ones a shield absorbed
+    pub hits: u32,
+    pub pickups: u32,
+    pub near_misses: u32,
+    pub cause_of_death: Option<ObstacleKind>,
+}
+
+#[derive(Resource)]
+pub struct HurtConfig {
+    pub invulnerability_secs: f32,
+    pub blink_interval_secs: f32,
+    // pushed back and up when hit
+    pub knockback: Vec2,
+}
+
+impl Default for HurtConfig {
+    fn default() -> Self {
+        HurtConfig {
+            invulnerability_secs: 1.5,
+            blink_interval_secs: 0.1,
+            knockback: Vec2::new(60.0, 700.0),
+        }
+    }
+}
+
+// brief slow motion after a near miss
+#[derive(Resource)]
+pub struct NearMissFlash(pub Timer);
+
+impl Default for NearMissFlash {
+    fn default() -> Self {
+        let mut timer = Timer::from_seconds(0.35, TimerMode::Once);
+        timer.tick(timer.duration());
+        NearMissFlash(timer)
+    }
+}
+
+// screen shake, hit-stop and zoom punch
+#[derive(Resource)]
+pub struct CameraEffects {
+    // 0 to 1, the shake grows with its square
+    pub trauma: f32,
+    pub hit_stop: Timer,
+    pub zoom_punch: Timer,
+}
+
+impl Default for CameraEffects {
+    fn default() -> Self {
+        let mut hit_stop = Timer::from_seconds(0.08, TimerMode::Once);
+        hit_stop.tick(hit_stop.duration());
+        let mut zoom_punch = Timer::from_seconds(0.25, TimerMode::Once);
+        zoom_punch.tick(zoom_punch.duration());
+        CameraEffects {
+            trauma: 0.0,
+            hit_stop,
+            zoom_punch,
+        }
+    }
+}
+
+// saved between runs, see storage.rs
+#[derive(Resource, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct Settings {
+    // off for players sensitive to screen motion
+    pub camera_effects: bool,
+    // volumes from 0 to 1, music and sound effects are scaled by the master volume
+    pub master_volume: f32,
+    pub music_volume: f32,
+    pub sfx_volume: f32,
+    // jump, duck and pause buttons on screen, in place of touch gestures
+    pub touch_buttons: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            camera_effects: true,
+            master_volume: 0.8,
+            music_volume: 0.6,
+            sfx_volume: 0.8,
+            touch_buttons: false,
+        }
+    }
+}
+
+// totals over every run the player has played, and what they've unlocked, saved between sessions
+#[derive(Resource, Serialize, Deserialize, Default, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct Profile {
+    pub runs: u32,
+    // in points, like the score
+    pub distance: f32,
+    pub pterodactyls_knocked_down: u32,
+    pub cactus_arms_dislodged: u32,
+    // ids of the achievements unlocked
+    pub unlocked: BTreeSet<String>,
+}
+
+#[derive(Resource)]
+pub struct Achievements(pub Handle<AchievementList>);
+
+// game speed before temporary effects such as slow-mo are applied
+#[derive(Resource)]
+pub struct BaseSpeed(pub f32);
+
+// sets the starting speed and health
+#[derive(Resource, Serialize, Deserialize, Default, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+// who is playing, the built-in autopilot plays the demo behind the main menu
+#[derive(Resource, Serialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Controller {
+    Human,
+    Autopilot,
+    // a program outside the game, through the gym protocol
+    Agent,
+    // a network evolved by the trainer, loaded with --genome
+    Network,
+}
+
+#[derive(Resource)]
+pub struct Brain(pub Genome);
+
+// the part of the world the camera shows, centred on the origin
+#[derive(Resource)]
+pub struct VisibleArea {
+    pub half_size: Vec2,
+}
+
+impl Default for VisibleArea {
+    fn default() -> Self {
+        VisibleArea {
+            half_size: Vec2::new(WINDOW_WIDTH, WINDOW_HEIGHT) / 2.0,
+        }
+    }
+}
+
+pub struct ActivePowerUp {
+    pub timer: Timer,
+    pub stacks: u32,
+}
+
+#[derive(Resource, Default)]
+pub struct ActivePowerUps {
+    pub active: BTreeMap<PowerUpKind, ActivePowerUp>,
+    pub air_jump_used: bool,
+}
 
 #[derive(Resource, Clone)]
 pub struct HealthPickUpImg(pub Handle<Image>);
@@ -33,4 +234,31 @@ pub struct DinoDuck(pub Handle<Image>);
 pub struct DinoJump(pub Handle<Image>);
 
 #[derive(Resource, Clone)]
-pub struct DinoDie(pub Handle<Image>);
\ No newline at end of file
+pub struct DinoDie(pub Handle<Image>);
+#[derive(Resource)]
+pub struct SoundEffects {
+    pub jump: Handle<SynthSound>,
+    pub land: Handle<SynthSound>,
+    pub duck: Handle<SynthSound>,
+    pub hit: Handle<SynthSound>,
+    pub pickup: Handle<SynthSound>,
+    pub pterodactyl_die: Handle<SynthSound>,
+    pub milestone: Handle<SynthSound>,
+}
+
+#[derive(Resource)]
+pub struct ParticleEmitters {
+    pub dust: Handle<EmitterConfig>,
+    pub sparks: Handle<EmitterConfig>,
+    pub feathers: Handle<EmitterConfig>,
+    pub crumbs: Handle<EmitterConfig>,
+}
+
+#[derive(Resource)]
+pub struct Biomes {
+    pub handles: Vec<Handle<Biome>>,
+    pub current: usize,
+    // the biome whose layers are on screen
+    pub spawned: Option<usize>,
+    pub next_transition: f32,
+}
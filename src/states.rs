@@ -2,6 +2,8 @@ use bevy::prelude::States;
 
 #[derive(States, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum GameState {
+    // waits for the textures and other assets before the run starts
+    Loading,
     InGame,
     GameOver,
 }
\ No newline at end of file
@@ -0,0 +1,345 @@
+use bevy::asset::Asset;
+use bevy::audio::{Decodable, Source};
+use bevy::reflect::TypePath;
+use std::f32::consts::TAU;
+use std::sync::Arc;
+use std::time::Duration;
+
+// sfxr-style sound effects, rendered from a handful of parameters instead of shipped as audio files
+
+pub const SAMPLE_RATE: u32 = 22050;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Waveform {
+    Square,
+    Sawtooth,
+    Triangle,
+    Sine,
+    // a new random level every period, so the frequency sets how rough it sounds
+    Noise,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SfxParams {
+    pub wave: Waveform,
+    // envelope, in seconds
+    pub attack: f32,
+    pub sustain: f32,
+    pub decay: f32,
+    // the pitch slides from the start to the end frequency over the whole sound
+    pub frequency: f32,
+    pub end_frequency: f32,
+    // fraction of each period a square wave is high
+    pub duty: f32,
+    // as a fraction of the frequency
+    pub vibrato_depth: f32,
+    pub vibrato_speed: f32,
+    // jumps the pitch by this factor after arpeggio_secs, 0 for no jump
+    pub arpeggio: f32,
+    pub arpeggio_secs: f32,
+    // 1 lets everything through, lower values muffle
+    pub lowpass: f32,
+    pub volume: f32,
+}
+
+const BASE: SfxParams = SfxParams {
+    wave: Waveform::Square,
+    attack: 0.0,
+    sustain: 0.0,
+    decay: 0.1,
+    frequency: 440.0,
+    end_frequency: 440.0,
+    duty: 0.5,
+    vibrato_depth: 0.0,
+    vibrato_speed: 0.0,
+    arpeggio: 0.0,
+    arpeggio_secs: 0.0,
+    lowpass: 1.0,
+    volume: 0.3,
+};
+
+pub const JUMP: SfxParams = SfxParams {
+    decay: 0.15,
+    frequency: 300.0,
+    end_frequency: 750.0,
+    ..BASE
+};
+
+pub const LAND: SfxParams = SfxParams {
+    wave: Waveform::Noise,
+    decay: 0.09,
+    frequency: 2000.0,
+    end_frequency: 600.0,
+    lowpass: 0.1,
+    volume: 0.9,
+    ..BASE
+};
+
+pub const DUCK: SfxParams = SfxParams {
+    decay: 0.08,
+    frequency: 500.0,
+    end_frequency: 220.0,
+    duty: 0.3,
+    volume: 0.25,
+    ..BASE
+};
+
+pub const HIT: SfxParams = SfxParams {
+    wave: Waveform::Noise,
+    sustain: 0.05,
+    decay: 0.3,
+    frequency: 3000.0,
+    end_frequency: 400.0,
+    lowpass: 0.4,
+    volume: 0.7,
+    ..BASE
+};
+
+pub const PICKUP: SfxParams = SfxParams {
+    sustain: 0.06,
+    decay: 0.14,
+    frequency: 880.0,
+    end_frequency: 880.0,
+    arpeggio: 1.5,
+    arpeggio_secs: 0.06,
+    volume: 0.25,
+    ..BASE
+};
+
+pub const PTERODACTYL_DIE: SfxParams = SfxParams {
+    wave: Waveform::Sawtooth,
+    sustain: 0.05,
+    decay: 0.4,
+    frequency: 900.0,
+    end_frequency: 180.0,
+    vibrato_depth: 0.08,
+    vibrato_speed: 30.0,
+    volume: 0.35,
+    ..BASE
+};
+
+pub const MILESTONE: SfxParams = SfxParams {
+    sustain: 0.12,
+    decay: 0.25,
+    frequency: 659.3,
+    end_frequency: 659.3,
+    arpeggio: 1.5,
+    arpeggio_secs: 0.09,
+    duty: 0.25,
+    volume: 0.22,
+    ..BASE
+};
+
+pub const PRESETS: [(&str, SfxParams); 7] = [
+    ("jump", JUMP),
+    ("land", LAND),
+    ("duck", DUCK),
+    ("hit", HIT),
+    ("pickup", PICKUP),
+    ("pterodactyl_die", PTERODACTYL_DIE),
+    ("milestone", MILESTONE),
+];
+
+impl SfxParams {
+    pub fn duration_secs(&self) -> f32 {
+        self.attack + self.sustain + self.decay
+    }
+
+    fn envelope(&self, t: f32) -> f32 {
+        if t < self.attack {
+            t / self.attack
+        } else if t < self.attack + self.sustain {
+            1.0
+        } else {
+            (1.0 - (t - self.attack - self.sustain) / self.decay).max(0.0).powi(2)
+        }
+    }
+
+    fn frequency_at(&self, t: f32) -> f32 {
+        let progress = t / self.duration_secs();
+        // exponential, so the slide sounds even to the ear
+        let mut frequency = self.frequency * (self.end_frequency / self.frequency).powf(progress);
+        if self.arpeggio > 0.0 && t >= self.arpeggio_secs {
+            frequency *= self.arpeggio;
+        }
+        frequency * (1.0 + self.vibrato_depth * (TAU * self.vibrato_speed * t).sin())
+    }
+
+    pub fn render(&self) -> Vec<f32> {
+        let count = (self.duration_secs() * SAMPLE_RATE as f32) as usize;
+        let mut samples = Vec::with_capacity(count);
+        let mut phase = 0.0f32;
+        let mut noise = NoiseSource::default();
+        let mut noise_level = noise.next();
+        let mut filtered = 0.0;
+
+        for i in 0..count {
+            let t = i as f32 / SAMPLE_RATE as f32;
+            phase += self.frequency_at(t) / SAMPLE_RATE as f32;
+            if phase >= 1.0 {
+                phase -= phase.floor();
+                noise_level = noise.next();
+            }
+            let raw = match self.wave {
+                Waveform::Square => if phase < self.duty { 1.0 } else { -1.0 },
+                Waveform::Sawtooth => 2.0 * phase - 1.0,
+                Waveform::Triangle => 4.0 * (phase - 0.5).abs() - 1.0,
+                Waveform::Sine => (TAU * phase).sin(),
+                Waveform::Noise => noise_level,
+            };
+            filtered += self.lowpass * (raw - filtered);
+            samples.push(filtered * self.envelope(t) * self.volume);
+        }
+        samples
+    }
+}
+
+// xorshift, so the same parameters always sound the same
+struct NoiseSource(u32);
+
+impl Default for NoiseSource {
+    fn default() -> Self {
+        NoiseSource(0x9E37_79B9)
+    }
+}
+
+impl NoiseSource {
+    fn next(&mut self) -> f32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        self.0 as f32 / u32::MAX as f32 * 2.0 - 1.0
+    }
+}
+
+fn note(base: f32, semitones: i32) -> f32 {
+    base * 2f32.powf(semitones as f32 / 12.0)
+}
+
+fn mix_into(out: &mut [f32], start: usize, samples: &[f32]) {
+    for (out, sample) in out.iter_mut().skip(start).zip(samples) {
+        *out += sample;
+    }
+}
+
+// an eight bar loop at 150 bpm in A minor, played with the same voices as the sound effects
+pub fn music() -> Vec<f32> {
+    const BEAT_SECS: f32 = 60.0 / 150.0;
+    const BASS: [i32; 16] = [0, 0, 7, 7, 5, 5, 3, 3, 0, 0, 7, 7, 8, 8, 7, 7];
+    const LEAD: [Option<i32>; 32] = [
+        Some(12), None, Some(15), Some(17), Some(19), None, Some(17), Some(15),
+        Some(12), None, Some(10), Some(12), Some(15), None, None, None,
+        Some(12), None, Some(15), Some(17), Some(19), None, Some(22), Some(19),
+        Some(24), None, Some(22), Some(19), Some(17), None, Some(15), None,
+    ];
+    let beat = (BEAT_SECS * SAMPLE_RATE as f32) as usize;
+    let mut out = vec![0.0; beat * LEAD.len()];
+
+    for (i, &semitones) in BASS.iter().enumerate() {
+        let frequency = note(55.0, semitones);
+        let bass = SfxParams {
+            wave: Waveform::Triangle,
+            attack: 0.005,
+            sustain: BEAT_SECS * 1.8,
+            decay: 0.05,
+            frequency,
+            end_frequency: frequency,
+            volume: 0.35,
+            ..BASE
+        };
+        mix_into(&mut out, i * 2 * beat, &bass.render());
+    }
+    for (i, semitones) in LEAD.iter().enumerate() {
+        let Some(semitones) = *semitones else {
+            continue;
+        };
+        let frequency = note(220.0, semitones);
+        let lead = SfxParams {
+            attack: 0.005,
+            sustain: BEAT_SECS * 0.7,
+            decay: 0.1,
+            frequency,
+            end_frequency: frequency,
+            volume: 0.09,
+            ..BASE
+        };
+        mix_into(&mut out, i * beat, &lead.render());
+    }
+    for i in 0..LEAD.len() {
+        // hi-hat on every beat, louder on the downbeats
+        let hat = SfxParams {
+            wave: Waveform::Noise,
+            decay: 0.04,
+            frequency: 8000.0,
+            end_frequency: 8000.0,
+            lowpass: 0.6,
+            volume: if i % 2 == 0 { 0.3 } else { 0.15 },
+            ..BASE
+        };
+        mix_into(&mut out, i * beat, &hat.render());
+    }
+    out
+}
+
+// rendered samples, played through bevy's audio like any other audio asset
+#[derive(Asset, TypePath, Clone)]
+pub struct SynthSound {
+    samples: Arc<[f32]>,
+}
+
+impl SynthSound {
+    pub fn new(samples: Vec<f32>) -> Self {
+        SynthSound { samples: samples.into() }
+    }
+}
+
+impl From<SfxParams> for SynthSound {
+    fn from(params: SfxParams) -> Self {
+        SynthSound::new(params.render())
+    }
+}
+
+pub struct SynthDecoder {
+    samples: Arc<[f32]>,
+    position: usize,
+}
+
+impl Iterator for SynthDecoder {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        let sample = self.samples.get(self.position).copied();
+        self.position += 1;
+        sample
+    }
+}
+
+impl Source for SynthDecoder {
+    fn current_frame_len(&self) -> Option<usize> {
+        Some(self.samples.len().saturating_sub(self.position))
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        SAMPLE_RATE
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        Some(Duration::from_secs_f32(self.samples.len() as f32 / SAMPLE_RATE as f32))
+    }
+}
+
+impl Decodable for SynthSound {
+    type DecoderItem = f32;
+    type Decoder = SynthDecoder;
+
+    fn decoder(&self) -> SynthDecoder {
+        SynthDecoder {
+            samples: self.samples.clone(),
+            position: 0,
+        }
+    }
+}
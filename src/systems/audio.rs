@@ -0,0 +1,100 @@
+use crate::components::{Music, ObstacleKind};
+use crate::events::{AchievementUnlocked, ObstacleHit, PickupCollected, PlayerDamaged, PlayerDied, PlayerDucked, PlayerJumped, PlayerLanded, ScoreMilestone};
+use crate::resources::{Settings, SoundEffects};
+use bevy::audio::Volume;
+use bevy::prelude::*;
+use my_bevy_game::synth::{self, SynthSound};
+
+// the music only follows part of the game speed up, or it would turn into chipmunks
+const MUSIC_SPEED_FOLLOW: f32 = 0.25;
+const MIN_MUSIC_SPEED: f32 = 0.8;
+const MAX_MUSIC_SPEED: f32 = 1.5;
+
+impl Settings {
+    pub fn music_volume(&self) -> Volume {
+        Volume::Linear(self.master_volume * self.music_volume)
+    }
+
+    pub fn sfx_volume(&self) -> Volume {
+        Volume::Linear(self.master_volume * self.sfx_volume)
+    }
+}
+
+// everything is synthesized on startup, so no audio files ship with the game
+pub fn load_audio(mut commands: Commands, mut sounds: ResMut<Assets<SynthSound>>, settings: Res<Settings>) {
+    commands.insert_resource(SoundEffects {
+        jump: sounds.add(synth::JUMP),
+        land: sounds.add(synth::LAND),
+        duck: sounds.add(synth::DUCK),
+        hit: sounds.add(synth::HIT),
+        pickup: sounds.add(synth::PICKUP),
+        pterodactyl_die: sounds.add(synth::PTERODACTYL_DIE),
+        milestone: sounds.add(synth::MILESTONE),
+    });
+    commands.spawn((
+        Music,
+        AudioPlayer(sounds.add(SynthSound::new(synth::music()))),
+        PlaybackSettings::LOOP.with_volume(settings.music_volume()),
+    ));
+}
+
+pub fn play_sound(commands: &mut Commands, sound: &Handle<SynthSound>, settings: &Settings) {
+    commands.spawn((
+        AudioPlayer(sound.clone()),
+        PlaybackSettings::DESPAWN.with_volume(settings.sfx_volume()),
+    ));
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn play_sound_effects(
+    mut commands: Commands,
+    sounds: Res<SoundEffects>,
+    settings: Res<Settings>,
+    mut jumped: EventReader<PlayerJumped>,
+    mut landed: EventReader<PlayerLanded>,
+    mut ducked: EventReader<PlayerDucked>,
+    mut damaged: EventReader<PlayerDamaged>,
+    mut died: EventReader<PlayerDied>,
+    mut pickups: EventReader<PickupCollected>,
+    mut obstacle_hits: EventReader<ObstacleHit>,
+    mut milestones: EventReader<ScoreMilestone>,
+    mut achievements: EventReader<AchievementUnlocked>,
+) {
+    // one of each per frame is plenty
+    let hits = obstacle_hits.read().filter(|hit| hit.kind == ObstacleKind::Pterodactyl).count();
+    let played = [
+        (jumped.read().count(), &sounds.jump),
+        (landed.read().count(), &sounds.land),
+        (ducked.read().count(), &sounds.duck),
+        (damaged.read().count() + died.read().count(), &sounds.hit),
+        (pickups.read().count(), &sounds.pickup),
+        (hits, &sounds.pterodactyl_die),
+        (milestones.read().count() + achievements.read().count(), &sounds.milestone),
+    ];
+    for (count, sound) in played {
+        if count > 0 {
+            play_sound(&mut commands, sound, &settings);
+        }
+    }
+}
+
+pub fn update_music(
+    time: Res<Time<Virtual>>,
+    settings: Res<Settings>,
+    mut music: Query<&mut AudioSink, With<Music>>,
+) {
+    let Ok(mut sink) = music.single_mut() else {
+        return;
+    };
+    // quiet while paused and on the game over screen
+    if time.is_paused() {
+        sink.pause();
+    } else {
+        sink.play();
+    }
+    let speed = 1.0 + (time.relative_speed() - 1.0) * MUSIC_SPEED_FOLLOW;
+    sink.set_speed(speed.clamp(MIN_MUSIC_SPEED, MAX_MUSIC_SPEED));
+    if settings.is_changed() {
+        sink.set_volume(settings.music_volume());
+    }
+}
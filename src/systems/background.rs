@@ -1,6 +1,9 @@
+use crate::assets::{tint_color, Biome, LayerAnchor, LayerKind};
+use crate::components::{BiomeLayer, Layer};
+use crate::constants::{GAME_SPEED, GROUND_LEVEL};
+use crate::resources::{Biomes, Score};
+use crate::systems::game::popup::spawn_popup;
 use bevy::asset::AssetServer;
-use crate::components::Layer;
-use crate::constants::{GAME_SPEED, GROUND_LEVEL, WINDOW_WIDTH};
 use bevy::math::Vec2;
 use bevy::prelude::*;
 use bevy::sprite::Anchor;
@@ -8,138 +11,230 @@ use bevy::sprite::Anchor;
 const IMG_SIZE_X: f32 = 1920.0;
 const IMG_SIZE_Y: f32 = 1080.0;
 
-// https://bevy.org/examples/2d-rendering/sprite-tile/
+const BIOME_FILES: [&str; 4] = [
+    "biomes/desert.biome.ron",
+    "biomes/jungle.biome.ron",
+    "biomes/snow.biome.ron",
+    "biomes/volcano.biome.ron",
+];
+// a new biome every 30,000 pixels, close to a minute at the starting speed
+const BIOME_DISTANCE: f32 = 30_000.0;
+const BIOME_FADE_SECS: f32 = 3.0;
+// incoming layers are drawn just in front of the ones they replace
+const FADE_IN_Z_OFFSET: f32 = 0.5;
+const TILED: SpriteImageMode = SpriteImageMode::Tiled {
+    tile_x: true,
+    tile_y: false,
+    stretch_value: 1.0,
+};
+const POPUP_COLOR: Color = Color::srgb(0.9, 0.9, 1.0);
+
+// tiled sprites are three images wide, enough to cover the window wherever the pattern is
+const TILED_WIDTH: f32 = 3.0 * IMG_SIZE_X;
+
+// the offset only ever moves within one image, and in double precision so rounding doesn't add up over a long run
+pub fn advance_offset(offset: f64, speed: f32, delta_secs: f64) -> f64 {
+    (offset + speed as f64 * delta_secs).rem_euclid(IMG_SIZE_X as f64)
+}
+
+// tiled sprites slide left and jump back by exactly one image, which looks the same
 pub fn scroll_background(
-    mut layers: Query<(&mut Sprite, &mut Layer)>,
+    mut layers: Query<(&mut Transform, &mut Layer)>,
     time: Res<Time>,
 ) {
-    for (mut sprite, mut layer) in layers.iter_mut() {
-        layer.current_size_x += layer.speed_scale * 2.0 * GAME_SPEED * time.delta_secs();
-        if layer.current_size_x >= 2.0 * (IMG_SIZE_X + WINDOW_WIDTH) {
-            layer.current_size_x = (layer.current_size_x % IMG_SIZE_X) + IMG_SIZE_X;
-        }
-        sprite.custom_size = Some(Vec2::new(layer.current_size_x, IMG_SIZE_Y));
+    for (mut transform, mut layer) in layers.iter_mut() {
+        layer.offset = advance_offset(layer.offset, layer.speed_scale * GAME_SPEED, time.delta_secs_f64());
+        transform.translation.x = -layer.offset as f32;
     }
 }
 
 pub fn initialize_background(mut commands: Commands, asset_server: Res<AssetServer>) {
-    // Ground
+    commands.insert_resource(Biomes {
+        handles: BIOME_FILES.iter().map(|path| asset_server.load(*path)).collect(),
+        current: 0,
+        spawned: None,
+        next_transition: BIOME_DISTANCE,
+    });
+}
+
+impl Biomes {
+    // back to the first biome, crossfading if it isn't already on screen
+    pub fn restart(&mut self) {
+        self.current = 0;
+        self.next_transition = BIOME_DISTANCE;
+    }
+}
+
+pub fn advance_biome(score: Res<Score>, mut biomes: ResMut<Biomes>) {
+    if score.distance >= biomes.next_transition {
+        biomes.current = (biomes.current + 1) % biomes.handles.len();
+        biomes.next_transition += BIOME_DISTANCE;
+    }
+}
+
+// spawns the layers of the current biome once it has loaded, fading them in over the old ones
+pub fn spawn_biome_layers(
+    mut commands: Commands,
+    mut biomes: ResMut<Biomes>,
+    biome_assets: Res<Assets<Biome>>,
+    layers: Query<(&BiomeLayer, &Layer)>,
+) {
+    if biomes.spawned == Some(biomes.current) {
+        return;
+    }
+    let Some(biome) = biome_assets.get(&biomes.handles[biomes.current]) else {
+        return;
+    };
+    // the first biome is shown straight away
+    let first = biomes.spawned.is_none();
+    let fade = if first { 1.0 } else { 0.0 };
+    let z_offset = if first { 0.0 } else { FADE_IN_Z_OFFSET };
+    // incoming layers line up with the ones they cover so the crossfade doesn't ghost
+    let offset_at = |z: f32| {
+        layers
+            .iter()
+            .find(|(layer, _)| layer.z == z)
+            .map_or(0.0, |(_, layer)| layer.offset)
+    };
+
+    for desc in biome.layers.iter() {
+        let mut layer = commands.spawn((
+            Sprite {
+                image: desc.handle.clone(),
+                image_mode: if desc.tiling { TILED } else { SpriteImageMode::Auto },
+                custom_size: desc.tiling.then_some(Vec2::new(TILED_WIDTH, IMG_SIZE_Y)),
+                anchor: anchor(desc.anchor),
+                ..default()
+            },
+            BiomeLayer {
+                kind: desc.kind,
+                tint: tint_color(desc.tint),
+                fade,
+                z: desc.z,
+                biome: biomes.current,
+            },
+            Transform::from_xyz(0.0, desc.y_offset, desc.z + z_offset),
+        ));
+        if desc.tiling {
+            layer.insert(Layer {
+                offset: offset_at(desc.z),
+                speed_scale: desc.speed,
+            });
+        }
+    }
+
+    let ground = &biome.ground;
     commands.spawn((
         Sprite {
-            image: asset_server.load("sand3.png"),
-            image_mode: SpriteImageMode::Tiled {
-                tile_x: true,
-                tile_y: false,
-                stretch_value: 1.0,
-            },
+            image: ground.handle.clone(),
+            image_mode: TILED,
+            custom_size: Some(Vec2::new(TILED_WIDTH, IMG_SIZE_Y)),
             anchor: Anchor::BottomCenter,
             ..default()
         },
+        BiomeLayer {
+            kind: LayerKind::Scenery,
+            tint: tint_color(ground.tint),
+            fade,
+            z: -1.0,
+            biome: biomes.current,
+        },
         Layer {
-            current_size_x: IMG_SIZE_X,
+            offset: offset_at(-1.0),
             speed_scale: 1.0,
         },
-        Transform::from_xyz(0.0, GROUND_LEVEL - 150.0, -1.0),
+        Transform::from_xyz(0.0, GROUND_LEVEL + ground.y_offset, -1.0 + z_offset),
     ));
 
-    commands.spawn((
-        Sprite {
-            image: asset_server.load("3 Layer3.png"),
-            image_mode: SpriteImageMode::Tiled {
-                tile_x: true,
-                tile_y: false,
-                stretch_value: 1.0,
-            },
-            ..default()
-        },
-        Layer {
-            current_size_x: IMG_SIZE_X,
-            speed_scale: 0.6,
-        },
-        Transform::from_xyz(0.0, 0.0, -3.0),
-    ));
+    if !first {
+        spawn_popup(&mut commands, &biome.name, POPUP_COLOR);
+    }
+    biomes.spawned = Some(biomes.current);
+}
 
-    commands.spawn((
-        Sprite {
-            image: asset_server.load("4 Layer4.png"),
-            image_mode: SpriteImageMode::Tiled {
-                tile_x: true,
-                tile_y: false,
-                stretch_value: 1.0,
-            },
-            ..default()
-        },
-        Layer {
-            current_size_x: IMG_SIZE_X,
-            speed_scale: 0.5,
-        },
-        Transform::from_xyz(0.0, 0.0, -4.0),
-    ));
+pub fn crossfade_biome_layers(
+    mut commands: Commands,
+    time: Res<Time>,
+    biomes: Res<Biomes>,
+    mut layers: Query<(Entity, &mut BiomeLayer, &mut Transform)>,
+) {
+    let mut fading = false;
+    for (_, mut layer, mut transform) in layers.iter_mut() {
+        if Some(layer.biome) == biomes.spawned && layer.fade < 1.0 {
+            layer.fade = (layer.fade + time.delta_secs() / BIOME_FADE_SECS).min(1.0);
+            fading |= layer.fade < 1.0;
+            if layer.fade >= 1.0 {
+                transform.translation.z = layer.z;
+            }
+        }
+    }
+    // the old layers stay opaque underneath until the new ones cover them
+    if !fading {
+        for (entity, layer, _) in layers.iter() {
+            if Some(layer.biome) != biomes.spawned {
+                commands.entity(entity).try_despawn();
+            }
+        }
+    }
+}
 
-    commands.spawn((
-        Sprite {
-            image: asset_server.load("5 Mountains.png"),
-            image_mode: SpriteImageMode::Tiled {
-                tile_x: true,
-                tile_y: false,
-                stretch_value: 1.0,
-            },
-            ..default()
-        },
-        Layer {
-            current_size_x: IMG_SIZE_X,
-            speed_scale: 0.3,
-        },
-        Transform::from_xyz(0.0, 0.0, -5.0),
-    ));
+fn anchor(anchor: LayerAnchor) -> Anchor {
+    match anchor {
+        LayerAnchor::Center => Anchor::Center,
+        LayerAnchor::BottomCenter => Anchor::BottomCenter,
+    }
+}
 
-    commands.spawn((
-        Sprite {
-            image: asset_server.load("6 Sun.png"),
-            ..default()
-        },
-        Transform::from_xyz(0.0, 0.0, -6.0),
-    ));
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    commands.spawn((
-        Sprite {
-            image: asset_server.load("7 Clouds.png"),
-            image_mode: SpriteImageMode::Tiled {
-                tile_x: true,
-                tile_y: false,
-                stretch_value: 1.0,
-            },
-            ..default()
-        },
-        Layer {
-            current_size_x: IMG_SIZE_X,
-            speed_scale: 0.1,
-        },
-        Transform::from_xyz(0.0, 0.0, -7.0),
-    ));
+    // the offset the layer should be at, worked out in one go with double precision
+    fn expected_offset(speed: f32, secs: f64) -> f64 {
+        (speed as f64 * secs).rem_euclid(IMG_SIZE_X as f64)
+    }
 
-    commands.spawn((
-        Sprite {
-            image: asset_server.load("8 Stars.png"),
-            image_mode: SpriteImageMode::Tiled {
-                tile_x: true,
-                tile_y: false,
-                stretch_value: 1.0,
-            },
-            ..default()
-        },
-        Layer {
-            current_size_x: IMG_SIZE_X,
-            speed_scale: 0.1,
-        },
-        Transform::from_xyz(0.0, 0.0, -8.0),
-    ));
+    // distance between two offsets on the wrapping image
+    fn wrapped_gap(a: f64, b: f64) -> f64 {
+        let gap = (a - b).rem_euclid(IMG_SIZE_X as f64);
+        gap.min(IMG_SIZE_X as f64 - gap)
+    }
 
-    commands.spawn((
-        Sprite {
-            image: asset_server.load("9 Background.png"),
-            ..default()
-        },
-        Transform::from_xyz(0.0, 0.0, -9.0),
-    ));
-}
\ No newline at end of file
+    fn simulate(speed: f32, fps: u32, secs: u32) -> f64 {
+        let delta = 1.0 / fps as f64;
+        let mut offset = 0.0;
+        for _ in 0..fps * secs {
+            offset = advance_offset(offset, speed, delta);
+        }
+        offset
+    }
+
+    #[test]
+    fn offset_moves_smoothly_over_hours() {
+        // ten hours at three times the starting speed, the wrap never shows as a jump
+        let speed = 3.0 * GAME_SPEED;
+        let delta = 1.0 / 60.0;
+        let mut offset = 0.0;
+        for _ in 0..60 * 60 * 60 * 10 {
+            let next = advance_offset(offset, speed, delta);
+            assert!((0.0..IMG_SIZE_X as f64).contains(&next), "offset {next} left the image");
+            assert!((wrapped_gap(next, offset) - speed as f64 * delta).abs() < 0.001, "jumped from {offset} to {next}");
+            offset = next;
+        }
+    }
+
+    #[test]
+    fn offset_does_not_depend_on_frame_rate() {
+        for speed in [0.1 * GAME_SPEED, 0.6 * GAME_SPEED, GAME_SPEED, 3.0 * GAME_SPEED] {
+            let secs = 60 * 60;
+            let expected = expected_offset(speed, secs as f64);
+            for fps in [30, 60, 144, 240] {
+                let offset = simulate(speed, fps, secs);
+                assert!(
+                    wrapped_gap(offset, expected) < 0.1,
+                    "speed {speed} at {fps} fps: offset {offset}, expected {expected}"
+                );
+            }
+        }
+    }
+}
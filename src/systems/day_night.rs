@@ -0,0 +1,48 @@
+use crate::assets::LayerKind;
+use crate::components::BiomeLayer;
+use crate::resources::Score;
+use bevy::prelude::*;
+use std::f32::consts::{PI, TAU};
+
+// a full day and night every 40,000 pixels, a little over a minute at the starting speed
+const CYCLE_DISTANCE: f32 = 40_000.0;
+const NIGHT_TINT: Color = Color::srgb(0.25, 0.28, 0.5);
+const SKY_NIGHT_TINT: Color = Color::srgb(0.08, 0.1, 0.25);
+// the sun sprite is a full screen image, so the arc moves the whole image
+const SUN_ARC_WIDTH: f32 = 700.0;
+const SUN_ARC_HEIGHT: f32 = 300.0;
+
+// 0 at noon, 1 at midnight
+pub fn darkness(distance: f32) -> f32 {
+    let phase = (distance / CYCLE_DISTANCE).fract();
+    0.5 - 0.5 * (TAU * phase).cos()
+}
+
+// the biome tint lit by the time of day
+fn shade(tint: Color, light: Color) -> Color {
+    let (tint, light) = (tint.to_srgba(), light.to_srgba());
+    Color::srgb(tint.red * light.red, tint.green * light.green, tint.blue * light.blue)
+}
+
+pub fn cycle_day_night(
+    score: Res<Score>,
+    mut layers: Query<(&BiomeLayer, &mut Sprite, &mut Transform)>,
+) {
+    let night = darkness(score.distance);
+    // noon is at the top of the arc, rising from the left before and setting to the right after
+    let phase = (score.distance / CYCLE_DISTANCE + 0.5).fract();
+
+    for (layer, mut sprite, mut transform) in layers.iter_mut() {
+        let (color, alpha) = match layer.kind {
+            LayerKind::Scenery => (shade(layer.tint, Color::WHITE.mix(&NIGHT_TINT, night)), 1.0),
+            LayerKind::Sky => (shade(layer.tint, Color::WHITE.mix(&SKY_NIGHT_TINT, night)), 1.0),
+            LayerKind::Stars => (layer.tint, night),
+            LayerKind::Sun => {
+                transform.translation.x = SUN_ARC_WIDTH * (phase * 2.0 - 1.0);
+                transform.translation.y = SUN_ARC_HEIGHT * ((phase * PI).sin() - 1.0);
+                (layer.tint, 1.0 - night)
+            }
+        };
+        sprite.color = color.with_alpha(alpha * layer.fade);
+    }
+}
@@ -0,0 +1,302 @@
+use crate::assets::{AchievementList, Condition, LifetimeStat, RunStat};
+use crate::components::{AchievementsScreen, CactusArm, IsHit, Toast};
+use crate::events::AchievementUnlocked;
+use crate::resources::{Achievements, Profile, RunStats, Score};
+use crate::storage;
+use crate::systems::game::score::DISTANCE_PER_POINT;
+use bevy::color::Color;
+use bevy::prelude::*;
+
+const PROFILE_KEY: &str = "profile";
+const TOAST_SECS: f32 = 4.0;
+const TOAST_FADE_SECS: f32 = 1.0;
+const TOAST_HEIGHT: f32 = 70.0;
+const UNLOCKED_COLOR: Color = Color::srgb(1.0, 0.85, 0.2);
+const LOCKED_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.5);
+
+impl Profile {
+    pub fn load() -> Self {
+        storage::load(PROFILE_KEY)
+            .and_then(|text| ron::from_str(&text).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        let saved = ron::ser::to_string_pretty(self, default())
+            .map_err(|e| e.to_string())
+            .and_then(|text| storage::save(PROFILE_KEY, &text));
+        if let Err(e) = saved {
+            warn!("could not save the profile: {e}");
+        }
+    }
+}
+
+impl Condition {
+    pub fn target(&self) -> f32 {
+        match *self {
+            Condition::Run(_, target) | Condition::Lifetime(_, target) => target,
+        }
+    }
+
+    // how far the player has got, the achievement unlocks once this reaches the target
+    pub fn progress(&self, run: &RunStats, score: u32, profile: &Profile) -> f32 {
+        match *self {
+            Condition::Run(stat, _) => match stat {
+                RunStat::Score => score as f32,
+                RunStat::Seconds => run.duration,
+                RunStat::TopSpeed => run.max_speed,
+                RunStat::Jumps => run.jumps as f32,
+                RunStat::Ducks => run.ducks as f32,
+                RunStat::Pickups => run.pickups as f32,
+                RunStat::NearMisses => run.near_misses as f32,
+                RunStat::Cleared(kind) => run.cleared.get(&kind).copied().unwrap_or_default() as f32,
+            },
+            Condition::Lifetime(stat, _) => match stat {
+                LifetimeStat::Runs => profile.runs as f32,
+                LifetimeStat::Distance => profile.distance,
+                LifetimeStat::PterodactylsKnockedDown => profile.pterodactyls_knocked_down as f32,
+                LifetimeStat::CactusArmsDislodged => profile.cactus_arms_dislodged as f32,
+            },
+        }
+    }
+}
+
+pub fn load_achievements(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(Achievements(asset_server.load("all.achievements.ron")));
+}
+
+// arms and pterodactyls start out with IsHit(false) and are knocked loose when it turns true
+pub fn count_knockdowns(knocked: Query<(&IsHit, Has<CactusArm>), Changed<IsHit>>, mut profile: ResMut<Profile>) {
+    for (is_hit, arm) in &knocked {
+        if !is_hit.0 {
+            continue;
+        }
+        if arm {
+            profile.cactus_arms_dislodged += 1;
+        } else {
+            profile.pterodactyls_knocked_down += 1;
+        }
+    }
+}
+
+// the run's distance goes towards the totals once it's over
+pub fn record_run(score: Res<Score>, mut profile: ResMut<Profile>) {
+    profile.runs += 1;
+    profile.distance += score.distance / DISTANCE_PER_POINT;
+    profile.save();
+}
+
+pub fn check_achievements(
+    lists: Res<Assets<AchievementList>>,
+    achievements: Res<Achievements>,
+    stats: Res<RunStats>,
+    score: Res<Score>,
+    mut profile: ResMut<Profile>,
+    mut unlocked: EventWriter<AchievementUnlocked>,
+) {
+    let Some(list) = lists.get(&achievements.0) else {
+        return;
+    };
+    let mut changed = false;
+    for achievement in &list.achievements {
+        if profile.unlocked.contains(&achievement.id)
+            || achievement.condition.progress(&stats, score.total(), &profile) < achievement.condition.target()
+        {
+            continue;
+        }
+        profile.unlocked.insert(achievement.id.clone());
+        unlocked.write(AchievementUnlocked {
+            name: achievement.name.clone(),
+            description: achievement.description.clone(),
+        });
+        changed = true;
+    }
+    if changed {
+        profile.save();
+    }
+}
+
+// stacked down from the top of the screen, newest at the bottom
+pub fn show_achievement_toasts(mut commands: Commands, mut unlocked: EventReader<AchievementUnlocked>, toasts: Query<(), With<Toast>>) {
+    for (i, achievement) in unlocked.read().enumerate() {
+        let slot = toasts.iter().count() + i;
+        commands
+            .spawn((
+                Toast(Timer::from_seconds(TOAST_SECS, TimerMode::Once)),
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Percent(0.),
+                    right: Val::Percent(0.),
+                    top: Val::Px(12. + slot as f32 * TOAST_HEIGHT),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                // over the game over and pause screens
+                GlobalZIndex(4),
+            ))
+            .with_children(|toast| {
+                toast
+                    .spawn((
+                        Node {
+                            flex_direction: FlexDirection::Column,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::axes(Val::Px(16.), Val::Px(6.)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+                    ))
+                    .with_children(|card| {
+                        card.spawn((
+                            Text(format!("Achievement unlocked: {}", achievement.name)),
+                            TextFont::from_font_size(24.0),
+                            TextColor(UNLOCKED_COLOR),
+                        ));
+                        card.spawn((
+                            Text(achievement.description.clone()),
+                            TextFont::from_font_size(16.0),
+                            TextColor(Color::WHITE),
+                        ));
+                    });
+            });
+    }
+}
+
+pub fn update_toasts(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut toasts: Query<(Entity, &mut Toast)>,
+    children: Query<&Children>,
+    mut texts: Query<&mut TextColor>,
+    mut backgrounds: Query<&mut BackgroundColor>,
+) {
+    for (entity, mut toast) in &mut toasts {
+        toast.0.tick(time.delta());
+        if toast.0.finished() {
+            commands.entity(entity).try_despawn();
+            continue;
+        }
+        let alpha = (toast.0.remaining_secs() / TOAST_FADE_SECS).min(1.0);
+        for part in children.iter_descendants(entity) {
+            if let Ok(mut color) = texts.get_mut(part) {
+                color.0.set_alpha(alpha);
+            }
+            if let Ok(mut background) = backgrounds.get_mut(part) {
+                background.0.set_alpha(0.7 * alpha);
+            }
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn toggle_achievements_screen(
+    mut commands: Commands,
+    mut time: ResMut<Time<Virtual>>,
+    screen_query: Query<(Entity, &AchievementsScreen)>,
+    lists: Res<Assets<AchievementList>>,
+    achievements: Res<Achievements>,
+    stats: Res<RunStats>,
+    score: Res<Score>,
+    profile: Res<Profile>,
+) {
+    if let Ok((entity, screen)) = screen_query.single() {
+        if !screen.was_paused {
+            time.unpause();
+        }
+        commands.entity(entity).despawn();
+        return;
+    }
+    let was_paused = time.is_paused();
+    time.pause();
+    let all = lists.get(&achievements.0).map(|list| list.achievements.as_slice()).unwrap_or_default();
+    let unlocked = all.iter().filter(|achievement| profile.unlocked.contains(&achievement.id)).count();
+    let totals = format!(
+        "{} runs, {:.0} points run, {} pterodactyls knocked down, {} cactus arms knocked off",
+        profile.runs, profile.distance, profile.pterodactyls_knocked_down, profile.cactus_arms_dislodged
+    );
+    commands
+        .spawn((
+            AchievementsScreen { was_paused },
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(0.),
+                right: Val::Percent(0.),
+                top: Val::Percent(0.),
+                bottom: Val::Percent(0.),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(4.),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+            // over the main menu and the game over screen
+            GlobalZIndex(6),
+        ))
+        .with_children(|screen| {
+            screen.spawn((
+                Text(format!("ACHIEVEMENTS {unlocked}/{}", all.len())),
+                TextFont::from_font_size(36.0),
+                TextColor(UNLOCKED_COLOR),
+            ));
+            screen.spawn((
+                Text(totals),
+                TextFont::from_font_size(18.0),
+                TextColor(Color::WHITE),
+                Node { margin: UiRect::bottom(Val::Px(12.)), ..default() },
+            ));
+            for achievement in all {
+                let condition = achievement.condition;
+                let (mark, color) = if profile.unlocked.contains(&achievement.id) {
+                    ("*", UNLOCKED_COLOR)
+                } else {
+                    ("-", LOCKED_COLOR)
+                };
+                // lifetime goals are worth showing progress towards, a run's best isn't kept
+                let progress = match condition {
+                    Condition::Lifetime(..) if mark == "-" => {
+                        format!(" ({:.0}/{:.0})", condition.progress(&stats, score.total(), &profile), condition.target())
+                    }
+                    _ => String::new(),
+                };
+                screen.spawn((
+                    Text(format!("{mark} {}: {}{progress}", achievement.name, achievement.description)),
+                    TextFont::from_font_size(18.0),
+                    TextColor(color),
+                ));
+            }
+            screen.spawn((
+                Text::new("A or Esc to close"),
+                TextFont::from_font_size(16.0),
+                TextColor(LOCKED_COLOR),
+                Node { margin: UiRect::top(Val::Px(12.)), ..default() },
+            ));
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::ObstacleKind;
+
+    #[test]
+    fn shipped_achievements_parse() {
+        let text = include_str!("../../../static/assets/all.achievements.ron");
+        let list: AchievementList = ron::from_str(text).unwrap();
+        assert!(!list.achievements.is_empty());
+        let mut ids: Vec<_> = list.achievements.iter().map(|achievement| &achievement.id).collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), list.achievements.len(), "achievement ids must be unique");
+    }
+
+    #[test]
+    fn progress_reads_the_run_and_the_profile() {
+        let mut run = RunStats::default();
+        run.cleared.insert(ObstacleKind::Boulder, 3);
+        let profile = Profile { cactus_arms_dislodged: 12, ..default() };
+        assert_eq!(Condition::Run(RunStat::Cleared(ObstacleKind::Boulder), 5.0).progress(&run, 0, &profile), 3.0);
+        assert_eq!(Condition::Run(RunStat::Cleared(ObstacleKind::Meteor), 1.0).progress(&run, 0, &profile), 0.0);
+        assert_eq!(Condition::Run(RunStat::Score, 500.0).progress(&run, 420, &profile), 420.0);
+        assert_eq!(Condition::Lifetime(LifetimeStat::CactusArmsDislodged, 10.0).progress(&run, 0, &profile), 12.0);
+    }
+}
@@ -0,0 +1,82 @@
+use crate::components::GameCamera;
+use crate::events::{PickupCollected, PlayerDamaged, PlayerDied};
+use crate::resources::{CameraEffects, Settings};
+use crate::systems::game::popup::spawn_popup;
+use bevy::prelude::*;
+
+const DAMAGE_TRAUMA: f32 = 0.5;
+const DEATH_TRAUMA: f32 = 0.8;
+// trauma lost per second
+const TRAUMA_DECAY: f32 = 1.2;
+const MAX_SHAKE_OFFSET: f32 = 24.0;
+const MAX_SHAKE_ANGLE: f32 = 0.04;
+const SHAKE_FREQUENCY: f32 = 25.0;
+// how much closer the camera gets at the peak of the punch
+const ZOOM_PUNCH: f32 = 0.04;
+const POPUP_COLOR: Color = Color::srgb(0.8, 0.8, 0.8);
+
+pub fn trigger_camera_effects(
+    mut damaged: EventReader<PlayerDamaged>,
+    mut died: EventReader<PlayerDied>,
+    mut pickups: EventReader<PickupCollected>,
+    mut effects: ResMut<CameraEffects>,
+    settings: Res<Settings>,
+) {
+    let damaged = damaged.read().count() > 0;
+    let died = died.read().count() > 0;
+    let picked_up = pickups.read().count() > 0;
+    if !settings.camera_effects {
+        return;
+    }
+    if damaged {
+        effects.trauma = (effects.trauma + DAMAGE_TRAUMA).min(1.0);
+        effects.hit_stop.reset();
+    }
+    if died {
+        effects.trauma = (effects.trauma + DEATH_TRAUMA).min(1.0);
+    }
+    if picked_up {
+        effects.zoom_punch.reset();
+    }
+}
+
+// effects run on real time so they play out the same during slow-mo, hit-stop and game over
+pub fn apply_camera_effects(
+    real_time: Res<Time<Real>>,
+    mut effects: ResMut<CameraEffects>,
+    mut cameras: Query<(&mut Transform, &mut Projection), With<GameCamera>>,
+) {
+    effects.trauma = (effects.trauma - TRAUMA_DECAY * real_time.delta_secs()).max(0.0);
+    effects.hit_stop.tick(real_time.delta());
+    effects.zoom_punch.tick(real_time.delta());
+
+    let Ok((mut transform, mut projection)) = cameras.single_mut() else {
+        return;
+    };
+    // smooth noise from a few out of step sine waves
+    let shake = effects.trauma * effects.trauma;
+    let t = real_time.elapsed_secs() * SHAKE_FREQUENCY;
+    transform.translation.x = MAX_SHAKE_OFFSET * shake * ((t * 1.0).sin() + (t * 2.3 + 1.7).sin()) / 2.0;
+    transform.translation.y = MAX_SHAKE_OFFSET * shake * ((t * 1.3 + 0.5).sin() + (t * 2.9 + 3.1).sin()) / 2.0;
+    transform.rotation = Quat::from_rotation_z(MAX_SHAKE_ANGLE * shake * (t * 0.8 + 2.2).sin());
+
+    if let Projection::Orthographic(orthographic) = projection.as_mut() {
+        // in quickly, out slowly
+        let punch = if effects.zoom_punch.finished() {
+            0.0
+        } else {
+            (1.0 - effects.zoom_punch.fraction()).powi(2)
+        };
+        orthographic.scale = 1.0 - ZOOM_PUNCH * punch;
+    }
+}
+
+pub fn toggle_camera_effects(mut commands: Commands, mut settings: ResMut<Settings>, mut effects: ResMut<CameraEffects>) {
+    settings.camera_effects = !settings.camera_effects;
+    settings.save();
+    if !settings.camera_effects {
+        *effects = CameraEffects::default();
+    }
+    let text = if settings.camera_effects { "Camera effects on" } else { "Camera effects off" };
+    spawn_popup(&mut commands, text, POPUP_COLOR);
+}
@@ -1,14 +1,16 @@
-use crate::components::{AnimationIndices, AnimationTimer, CactusRoot, GameOverText, Health, HealthPickup, Player, PlayerCollider, Pterodactyl};
-use crate::constants::INITIAL_HEALTH;
-use crate::resources::{DinoRun, ScoreOffset};
+use crate::components::{AnimationIndices, AnimationTimer, Boulder, CactusRoot, GameOverText, Health, Hurt, LavaPit, MeteorShadow, Particle, PauseText, Player, PlayerCollider, PowerUp, Pterodactyl};
+use crate::constants::PLAYER_X;
+use crate::events::RestartGame;
+use crate::resources::{ActivePowerUps, BaseSpeed, Biomes, Difficulty, DinoRun, RunStats, Score};
 use crate::states::GameState;
 use crate::states::GameState::InGame;
+use crate::systems::game::stats::summary;
 use crate::systems::player::animation::animate_run;
 use bevy::color::Color;
 use bevy::input::keyboard::KeyboardInput;
 use bevy::prelude::*;
 
-pub fn game_over(mut commands: Commands, mut score_offset: ResMut<ScoreOffset>,
+pub fn game_over(mut commands: Commands, score: Res<Score>, stats: Res<RunStats>,
                  mut time: ResMut<Time<Virtual>>) {
 
     commands
@@ -18,60 +20,92 @@ pub fn game_over(mut commands: Commands, mut score_offset: ResMut<ScoreOffset>,
             right: Val::Percent(10.),
             top: Val::Percent(15.),
             bottom: Val::Percent(15.),
+            flex_direction: FlexDirection::Column,
             justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(16.),
             ..default()
-        },))
+        },
+        GameOverText))
         .with_children(|builder| {
             builder.spawn((
-                Text(format!("Game Over. Score: {}\n Press Space bar to Play Again", (time.elapsed_secs() - score_offset.0).floor())),
+                Text(format!("Game Over. Score: {}\n Press Space bar or tap to Play Again", score.total())),
                 TextFont::from_font_size(48.0),
                 TextLayout::new_with_justify(JustifyText::Center).with_no_wrap(),
                 TextColor(Color::srgb(0.0, 0.5, 0.5)),
-                GameOverText,
+            ));
+            builder.spawn((
+                Text(summary(&stats)),
+                TextFont::from_font_size(22.0),
+                TextLayout::new_with_justify(JustifyText::Center).with_no_wrap(),
+                TextColor(Color::srgb(0.0, 0.5, 0.5)),
             ));
         });
-    score_offset.0 = time.elapsed_secs();
     time.pause();
 }
 
 
-// New system to restart the game
+pub fn restart_on_input(
+    mut events: EventReader<KeyboardInput>,
+    touches: Res<Touches>,
+    mut restarts: EventWriter<RestartGame>,
+) {
+    let space = events.read().any(|e| e.state.is_pressed() && e.key_code == KeyCode::Space);
+    if space || touches.any_just_pressed() {
+        restarts.write(RestartGame);
+    }
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn restart_game(
     mut commands: Commands,
-    mut events: EventReader<KeyboardInput>,
+    mut events: EventReader<RestartGame>,
     mut game_state: ResMut<NextState<GameState>>,
     mut time: ResMut<Time<Virtual>>,
-    mut player_query: Query<(&mut Sprite, &mut AnimationIndices, &mut AnimationTimer), With<Player>>,
+    mut player_query: Query<(Entity, &mut Sprite, &mut Transform, &mut AnimationIndices, &mut AnimationTimer), With<Player>>,
     mut health_query: Query<&mut Health, With<PlayerCollider>>,
-    obstacle_query: Query<Entity, Or<(With<CactusRoot>, With<Pterodactyl>, With<HealthPickup>)>>,
-    game_over_text_query: Query<Entity, With<GameOverText>>,
+    obstacle_query: Query<Entity, Or<(With<CactusRoot>, With<Pterodactyl>, With<PowerUp>, With<Boulder>, With<LavaPit>, With<MeteorShadow>, With<Particle>)>>,
+    text_query: Query<Entity, Or<(With<GameOverText>, With<PauseText>)>>,
     mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
     mut dino_run: Res<DinoRun>,
+    mut power_ups: ResMut<ActivePowerUps>,
+    mut score: ResMut<Score>,
+    mut stats: ResMut<RunStats>,
+    mut biomes: ResMut<Biomes>,
+    mut base_speed: ResMut<BaseSpeed>,
+    difficulty: Res<Difficulty>,
 ) {
-    for e in events.read() {
-        if e.state.is_pressed() && e.key_code == KeyCode::Space {
-            // Reset player health
-            let mut health = health_query.single_mut().unwrap();
-            health.0 = INITIAL_HEALTH;
+    if events.read().count() == 0 {
+        return;
+    }
+    // Reset player health
+    let mut health = health_query.single_mut().unwrap();
+    health.0 = difficulty.health();
+    base_speed.0 = difficulty.start_speed();
+    *power_ups = ActivePowerUps::default();
+    *score = Score::default();
+    *stats = RunStats::default();
+    biomes.restart();
 
-            // dino run animation
-            let (mut sprite, mut anim_indices, mut anim_timer) = player_query.single_mut().unwrap();
-            animate_run(&mut dino_run, &mut sprite, &mut anim_indices, &mut anim_timer, &mut texture_atlas_layouts, 0);
-            // Despawn all obstacles
-            for obstacle_entity in obstacle_query.iter() {
-                commands.entity(obstacle_entity).try_despawn();
-            }
+    // dino run animation
+    let (player, mut sprite, mut transform, mut anim_indices, mut anim_timer) = player_query.single_mut().unwrap();
+    commands.entity(player).remove::<Hurt>();
+    sprite.color.set_alpha(1.0);
+    transform.translation.x = PLAYER_X;
+    animate_run(&mut dino_run, &mut sprite, &mut anim_indices, &mut anim_timer, &mut texture_atlas_layouts, 0);
+    // Despawn all obstacles
+    for obstacle_entity in obstacle_query.iter() {
+        commands.entity(obstacle_entity).try_despawn();
+    }
 
-            // Despawn the "GAME OVER" text
-            for text_entity in game_over_text_query.iter() {
-                commands.entity(text_entity).try_despawn();
-            }
+    // Despawn the "GAME OVER" and pause texts
+    for text_entity in text_query.iter() {
+        commands.entity(text_entity).try_despawn();
+    }
 
-            // Reset game state
-            game_state.set(InGame);
+    // Reset game state
+    game_state.set(InGame);
 
-            // reset time
-            time.unpause();
-        }
-    }
+    // reset time
+    time.unpause();
 }
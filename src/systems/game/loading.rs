@@ -0,0 +1,117 @@
+use crate::components::{LoadingBar, LoadingScreen, LoadingText};
+use crate::resources::{Achievements, Biomes, CactusTexture, DinoDie, DinoDuck, DinoJump, DinoRun, HealthPickUpImg, ParticleEmitters, PterodactylDie, PterodactylFly};
+use crate::states::GameState;
+use bevy::asset::{LoadState, RecursiveDependencyLoadState, UntypedAssetId};
+use bevy::color::Color;
+use bevy::prelude::*;
+
+const LOADING_COLOR: Color = Color::srgb(0.0, 0.5, 0.5);
+const ERROR_COLOR: Color = Color::srgb(0.9, 0.2, 0.2);
+
+pub fn spawn_loading_screen(mut commands: Commands) {
+    commands
+        .spawn((
+            LoadingScreen,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(20.),
+                ..default()
+            },
+            BackgroundColor(Color::BLACK),
+            // above the score and health texts
+            GlobalZIndex(10),
+        ))
+        .with_children(|screen| {
+            screen.spawn((
+                LoadingText,
+                Text::new("Loading..."),
+                TextFont::from_font_size(32.0),
+                TextLayout::new_with_justify(JustifyText::Center),
+                TextColor(LOADING_COLOR),
+            ));
+            screen
+                .spawn((
+                    Node {
+                        width: Val::Px(400.),
+                        height: Val::Px(20.),
+                        border: UiRect::all(Val::Px(2.)),
+                        ..default()
+                    },
+                    BorderColor(LOADING_COLOR),
+                ))
+                .with_children(|bar| {
+                    bar.spawn((
+                        LoadingBar,
+                        Node {
+                            width: Val::Percent(0.),
+                            height: Val::Percent(100.),
+                            ..default()
+                        },
+                        BackgroundColor(LOADING_COLOR),
+                    ));
+                });
+        });
+}
+
+// starts the run once every asset and everything it refers to has loaded
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn update_loading(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    images: (Res<DinoRun>, Res<DinoDuck>, Res<DinoJump>, Res<DinoDie>, Res<PterodactylFly>, Res<PterodactylDie>, Res<CactusTexture>, Res<HealthPickUpImg>),
+    biomes: Res<Biomes>,
+    emitters: Res<ParticleEmitters>,
+    achievements: Res<Achievements>,
+    screen: Query<Entity, With<LoadingScreen>>,
+    mut bar: Query<&mut Node, With<LoadingBar>>,
+    mut text: Query<(&mut Text, &mut TextColor), With<LoadingText>>,
+    mut game_state: ResMut<NextState<GameState>>,
+    mut reported: Local<bool>,
+) {
+    let (run, duck, jump, die, fly, fall, cactus, pickup) = &images;
+    let mut ids: Vec<UntypedAssetId> = [&run.0, &duck.0, &jump.0, &die.0, &fly.0, &fall.0, &cactus.0, &pickup.0]
+        .into_iter()
+        .map(|handle| handle.id().untyped())
+        .collect();
+    ids.extend(biomes.handles.iter().map(|handle| handle.id().untyped()));
+    ids.extend([&emitters.dust, &emitters.sparks, &emitters.feathers, &emitters.crumbs].map(|handle| handle.id().untyped()));
+    ids.push(achievements.0.id().untyped());
+
+    let loaded = ids.iter().filter(|&&id| asset_server.is_loaded_with_dependencies(id)).count();
+    let errors: Vec<String> = ids
+        .iter()
+        .filter_map(|&id| match (asset_server.load_state(id), asset_server.recursive_dependency_load_state(id)) {
+            (LoadState::Failed(err), _) | (_, RecursiveDependencyLoadState::Failed(err)) => Some(err.to_string()),
+            _ => None,
+        })
+        .collect();
+
+    if let Ok(mut bar) = bar.single_mut() {
+        bar.width = Val::Percent(100. * loaded as f32 / ids.len() as f32);
+    }
+    if !errors.is_empty() {
+        // stay on the loading screen, the game would run blank without its assets
+        if !*reported {
+            *reported = true;
+            for err in &errors {
+                error!("{err}");
+            }
+            if let Ok((mut text, mut color)) = text.single_mut() {
+                text.0 = format!("Couldn't load the game:\n{}", errors.join("\n"));
+                color.0 = ERROR_COLOR;
+            }
+        }
+        return;
+    }
+    if loaded == ids.len() {
+        for entity in &screen {
+            commands.entity(entity).despawn();
+        }
+        game_state.set(GameState::InGame);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::components::MainMenu;
+use crate::events::RestartGame;
+use crate::resources::Controller;
+use bevy::color::Color;
+use bevy::prelude::*;
+
+const TITLE_COLOR: Color = Color::srgb(0.0, 0.5, 0.5);
+// how long the demo shows its game over screen before running again
+const DEMO_RESTART_SECS: f32 = 2.0;
+
+pub fn spawn_main_menu(mut commands: Commands) {
+    commands
+        .spawn((
+            MainMenu,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.),
+                top: Val::Percent(8.),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.),
+                ..default()
+            },
+            // below the loading screen
+            GlobalZIndex(5),
+        ))
+        .with_children(|menu| {
+            menu.spawn((
+                Text::new("DINO RUNNER"),
+                TextFont::from_font_size(64.0),
+                TextColor(TITLE_COLOR),
+            ));
+            menu.spawn((
+                Text::new("Press Space or tap to play\nEsc for settings, A for achievements"),
+                TextFont::from_font_size(24.0),
+                TextLayout::new_with_justify(JustifyText::Center),
+                TextColor(TITLE_COLOR),
+            ));
+        });
+}
+
+// the player takes over from the autopilot with a fresh run
+pub fn start_from_main_menu(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    menu_query: Query<Entity, With<MainMenu>>,
+    mut controller: ResMut<Controller>,
+    mut restarts: EventWriter<RestartGame>,
+) {
+    if !keys.any_just_pressed([KeyCode::Space, KeyCode::Enter])
+        && !mouse.just_pressed(MouseButton::Left)
+        && !touches.any_just_pressed()
+    {
+        return;
+    }
+    for menu in &menu_query {
+        commands.entity(menu).despawn();
+    }
+    *controller = Controller::Human;
+    restarts.write(RestartGame);
+}
+
+// the demo plays on after the autopilot dies
+pub fn restart_demo(time: Res<Time<Real>>, mut waited: Local<f32>, mut restarts: EventWriter<RestartGame>) {
+    *waited += time.delta_secs();
+    if *waited >= DEMO_RESTART_SECS {
+        *waited = 0.0;
+        restarts.write(RestartGame);
+    }
+}
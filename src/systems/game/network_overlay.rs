@@ -0,0 +1,117 @@
+use crate::components::{NetworkActionLabel, NetworkBar, NetworkOverlay, Player};
+use crate::constants::GROUND_LEVEL;
+use crate::resources::Brain;
+use bevy::color::Color;
+use bevy::prelude::*;
+use my_bevy_game::agent::{AgentAction, Observation};
+use my_bevy_game::neural::{input_names, inputs, ACTIONS};
+
+const LABEL_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.6);
+const CHOSEN_COLOR: Color = Color::srgb(1.0, 0.85, 0.2);
+const POSITIVE_COLOR: Color = Color::srgb(0.2, 0.8, 0.6);
+const NEGATIVE_COLOR: Color = Color::srgb(0.9, 0.4, 0.3);
+const TRACK_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.1);
+const OUTLINE_COLOR: Color = Color::srgb(0.2, 0.8, 0.6);
+const BAR_WIDTH: f32 = 80.0;
+const BAR_HEIGHT: f32 = 6.0;
+
+// a bar for each input and output of the network, growing right of the middle for positive values and
+// left for negative ones
+pub fn spawn_network_overlay(mut commands: Commands) {
+    let actions = ACTIONS.map(|action| format!("{action:?}").to_lowercase());
+    commands
+        .spawn((
+            NetworkOverlay,
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(8.),
+                top: Val::Px(8.),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(1.),
+                padding: UiRect::all(Val::Px(6.)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.35)),
+        ))
+        .with_children(|overlay| {
+            let rows = input_names()
+                .into_iter()
+                .enumerate()
+                .map(|(i, name)| (name, NetworkBar::Input(i)))
+                .chain(actions.into_iter().enumerate().map(|(i, name)| (name, NetworkBar::Output(i))));
+            for (name, bar) in rows {
+                overlay
+                    .spawn(Node {
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(6.),
+                        // a gap between what the network sees and what it does
+                        margin: UiRect::top(Val::Px(if bar == NetworkBar::Output(0) { 6. } else { 0. })),
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        let mut label = row.spawn((
+                            Text::new(name),
+                            TextFont::from_font_size(10.0),
+                            TextColor(LABEL_COLOR),
+                            Node { width: Val::Px(70.), ..default() },
+                        ));
+                        if let NetworkBar::Output(i) = bar {
+                            label.insert(NetworkActionLabel(i));
+                        }
+                        row.spawn((
+                            Node {
+                                width: Val::Px(BAR_WIDTH),
+                                height: Val::Px(BAR_HEIGHT),
+                                ..default()
+                            },
+                            BackgroundColor(TRACK_COLOR),
+                        ))
+                        .with_child((
+                            bar,
+                            Node {
+                                position_type: PositionType::Absolute,
+                                height: Val::Percent(100.),
+                                ..default()
+                            },
+                            BackgroundColor(POSITIVE_COLOR),
+                        ));
+                    });
+            }
+        });
+}
+
+pub fn update_network_overlay(
+    brain: Res<Brain>,
+    observation: Res<Observation>,
+    action: Res<AgentAction>,
+    mut bars: Query<(&NetworkBar, &mut Node, &mut BackgroundColor)>,
+    mut labels: Query<(&NetworkActionLabel, &mut TextColor)>,
+) {
+    let inputs = inputs(&observation);
+    let outputs = brain.0.outputs(&inputs);
+    for (bar, mut node, mut color) in &mut bars {
+        let value = match *bar {
+            NetworkBar::Input(i) => inputs[i],
+            NetworkBar::Output(i) => outputs[i],
+        }
+        .clamp(-1.0, 1.0);
+        let width = value.abs() * 50.0;
+        node.width = Val::Percent(width);
+        node.left = Val::Percent(if value < 0.0 { 50.0 - width } else { 50.0 });
+        color.0 = if value < 0.0 { NEGATIVE_COLOR } else { POSITIVE_COLOR };
+    }
+    for (label, mut color) in &mut labels {
+        color.0 = if ACTIONS[label.0] == *action { CHOSEN_COLOR } else { LABEL_COLOR };
+    }
+}
+
+// boxes around the obstacles the network is told about
+pub fn outline_observed_obstacles(observation: Res<Observation>, player_query: Query<&Transform, With<Player>>, mut gizmos: Gizmos) {
+    let Ok(player) = player_query.single() else {
+        return;
+    };
+    for obstacle in &observation.obstacles {
+        let centre = Vec2::new(player.translation.x + obstacle.x, GROUND_LEVEL + obstacle.y);
+        gizmos.rect_2d(centre, Vec2::new(obstacle.width, obstacle.height), OUTLINE_COLOR);
+    }
+}
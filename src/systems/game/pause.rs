@@ -1,36 +1,64 @@
-use crate::components::PauseText;
+use crate::components::{PauseText, TouchButtonsToggle};
+use crate::resources::Settings;
 use bevy::color::Color;
 use bevy::prelude::*;
 
-pub fn toggle_pause(mut time: ResMut<Time<Virtual>>, commands: Commands, query: Query<Entity, With<PauseText>>) {
+pub fn toggle_pause(
+    mut time: ResMut<Time<Virtual>>,
+    commands: Commands,
+    query: Query<Entity, With<PauseText>>,
+    settings: Res<Settings>,
+) {
     if time.is_paused() {
         time.unpause();
         hide_pause_text(commands, query);
     } else {
         time.pause();
-        show_pause_text(commands);
+        show_pause_text(commands, &settings);
     }
 }
 
-pub fn show_pause_text(mut commands: Commands) {
+pub fn show_pause_text(mut commands: Commands, settings: &Settings) {
     commands
-        .spawn((Node {
-            position_type: PositionType::Absolute,
-            left: Val::Percent(10.),
-            right: Val::Percent(10.),
-            top: Val::Percent(15.),
-            bottom: Val::Percent(15.),
-            justify_content: JustifyContent::Center,
-            ..default()
-        },))
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(10.),
+                right: Val::Percent(10.),
+                top: Val::Percent(15.),
+                bottom: Val::Percent(15.),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(24.),
+                ..default()
+            },
+            PauseText,
+        ))
         .with_children(|builder| {
             builder.spawn((
                 Text("You have paused the game".to_string()),
                 TextFont::from_font_size(16.0),
                 TextLayout::new_with_justify(JustifyText::Center).with_no_wrap(),
                 TextColor(Color::srgb(0.0, 0.5, 0.5)),
-                PauseText,
             ));
+            // the settings menu needs a keyboard, so touch players switch the buttons here
+            builder
+                .spawn((
+                    TouchButtonsToggle,
+                    Button,
+                    Node {
+                        padding: UiRect::axes(Val::Px(16.), Val::Px(8.)),
+                        border: UiRect::all(Val::Px(2.)),
+                        ..default()
+                    },
+                    BorderColor(Color::srgb(0.0, 0.5, 0.5)),
+                ))
+                .with_child((
+                    Text(touch_buttons_label(settings)),
+                    TextFont::from_font_size(16.0),
+                    TextColor(Color::srgb(0.0, 0.5, 0.5)),
+                ));
         });
 }
 
@@ -40,4 +68,26 @@ pub fn hide_pause_text(mut commands: Commands, query: Query<Entity, With<PauseTe
     }
 }
 
+fn touch_buttons_label(settings: &Settings) -> String {
+    format!("On-screen buttons: {}", if settings.touch_buttons { "on" } else { "off" })
+}
 
+#[allow(clippy::type_complexity)]
+pub fn toggle_touch_buttons(
+    toggles: Query<(&Interaction, &Children), (Changed<Interaction>, With<TouchButtonsToggle>)>,
+    mut texts: Query<&mut Text>,
+    mut settings: ResMut<Settings>,
+) {
+    for (interaction, children) in &toggles {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        settings.touch_buttons = !settings.touch_buttons;
+        settings.save();
+        for child in children {
+            if let Ok(mut text) = texts.get_mut(*child) {
+                text.0 = touch_buttons_label(&settings);
+            }
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use crate::components::Popup;
+use bevy::prelude::*;
+
+const POPUP_SECS: f32 = 1.0;
+const POPUP_RISE: f32 = 40.0;
+
+// short lived text in the middle of the screen that floats up and fades out
+pub fn spawn_popup(commands: &mut Commands, text: &str, color: Color) {
+    commands
+        .spawn((Node {
+            position_type: PositionType::Absolute,
+            left: Val::Percent(0.),
+            right: Val::Percent(0.),
+            top: Val::Percent(30.),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        Popup(Timer::from_seconds(POPUP_SECS, TimerMode::Once)),
+        ))
+        .with_children(|builder| {
+            builder.spawn((
+                Text(text.to_string()),
+                TextFont::from_font_size(32.0),
+                TextColor(color),
+            ));
+        });
+}
+
+pub fn update_popups(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut popups: Query<(Entity, &mut Popup, &mut Node, &Children)>,
+    mut colors: Query<&mut TextColor>,
+) {
+    for (entity, mut popup, mut node, children) in popups.iter_mut() {
+        popup.0.tick(time.delta());
+        if popup.0.finished() {
+            commands.entity(entity).try_despawn();
+            continue;
+        }
+        let progress = popup.0.fraction();
+        node.margin.top = Val::Px(-POPUP_RISE * progress);
+        for &child in children {
+            if let Ok(mut color) = colors.get_mut(child) {
+                color.0.set_alpha(1.0 - progress);
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use crate::components::{ObstacleKind, ScoreInfo};
+use crate::constants::GAME_SPEED;
+use crate::events::{NearMiss, ObstacleHit, PickupCollected, ScoreMilestone};
+use crate::resources::{ActivePowerUps, Score};
+use crate::systems::obstacles::near_miss::GRAZE_MARGIN;
+use bevy::prelude::*;
+
+// one point every 50 pixels, 10 points a second at the starting speed
+pub const DISTANCE_PER_POINT: f32 = 50.0;
+const MILESTONE_POINTS: u32 = 100;
+const FLASH_BLINK_SECS: f32 = 0.25;
+const MAX_COMBO: u32 = 5;
+
+pub const CACTUS_ARM_BONUS: f32 = 20.0;
+pub const PICKUP_BONUS: f32 = 10.0;
+// doubled for a miss by a whisker
+pub const NEAR_MISS_BONUS: f32 = 15.0;
+
+impl Score {
+    // bonuses in quick succession build up the combo, which multiplies each bonus
+    pub fn add_bonus(&mut self, points: f32) {
+        self.combo = if self.combo_timer.finished() { 1 } else { (self.combo + 1).min(MAX_COMBO) };
+        self.combo_timer.reset();
+        self.points += points * self.combo as f32;
+    }
+
+    pub fn total(&self) -> u32 {
+        self.points.floor() as u32
+    }
+}
+
+pub fn update_distance_score(
+    time: Res<Time<Virtual>>,
+    real_time: Res<Time<Real>>,
+    power_ups: Res<ActivePowerUps>,
+    mut score: ResMut<Score>,
+    mut milestones: EventWriter<ScoreMilestone>,
+) {
+    // virtual time already runs faster as the game speeds up
+    let distance = GAME_SPEED * time.delta_secs();
+    score.distance += distance;
+    score.points += distance / DISTANCE_PER_POINT * power_ups.score_multiplier();
+
+    if !time.is_paused() {
+        score.combo_timer.tick(real_time.delta());
+        score.flash.tick(real_time.delta());
+    }
+    if score.combo_timer.finished() {
+        score.combo = 0;
+    }
+
+    let milestone = score.total() / MILESTONE_POINTS;
+    if milestone > score.last_milestone {
+        score.last_milestone = milestone;
+        milestones.write(ScoreMilestone(milestone * MILESTONE_POINTS));
+    }
+}
+
+pub fn start_score_flash(mut milestones: EventReader<ScoreMilestone>, mut score: ResMut<Score>) {
+    if let Some(milestone) = milestones.read().last() {
+        score.flash_points = milestone.0;
+        score.flash.reset();
+    }
+}
+
+pub fn award_bonuses(
+    mut obstacle_hits: EventReader<ObstacleHit>,
+    mut pickups: EventReader<PickupCollected>,
+    mut near_misses: EventReader<NearMiss>,
+    mut score: ResMut<Score>,
+) {
+    for hit in obstacle_hits.read() {
+        // knocking the arms off a cactus takes style
+        if hit.kind == ObstacleKind::Cactus {
+            score.add_bonus(CACTUS_ARM_BONUS);
+        }
+    }
+    for _ in pickups.read() {
+        score.add_bonus(PICKUP_BONUS);
+    }
+    for near_miss in near_misses.read() {
+        let closeness = 1.0 - near_miss.distance.max(0.0) / GRAZE_MARGIN;
+        score.add_bonus(NEAR_MISS_BONUS * (1.0 + closeness));
+    }
+}
+
+pub fn render_score_info(
+    score: Res<Score>,
+    mut score_info_query: Query<(&mut Text, &mut TextColor), With<ScoreInfo>>,
+) {
+    let (mut text, mut color) = score_info_query.single_mut().unwrap();
+    // blink like the original when passing a milestone, showing the milestone itself
+    let flashing = !score.flash.finished();
+    text.0 = if flashing {
+        format!("\nScore: {}", score.flash_points)
+    } else if score.combo > 1 {
+        format!("\nScore: {}  Combo x{}", score.total(), score.combo)
+    } else {
+        format!("\nScore: {}", score.total())
+    };
+
+    let blinking = flashing && ((score.flash.elapsed_secs() / FLASH_BLINK_SECS) as u32).is_multiple_of(2);
+    color.0.set_alpha(if blinking { 0.0 } else { 1.0 });
+}
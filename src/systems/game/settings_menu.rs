@@ -0,0 +1,144 @@
+use crate::components::{SettingsMenu, SettingsMenuText};
+use crate::resources::{Settings, SoundEffects};
+use crate::storage;
+use crate::systems::audio::play_sound;
+use bevy::prelude::*;
+
+const SETTINGS_KEY: &str = "settings";
+const VOLUME_STEP: f32 = 0.1;
+const ROWS: usize = 5;
+
+impl Settings {
+    // falls back to the defaults if nothing was saved or it can't be read
+    pub fn load() -> Self {
+        storage::load(SETTINGS_KEY)
+            .and_then(|text| ron::from_str(&text).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        let saved = ron::ser::to_string_pretty(self, default())
+            .map_err(|e| e.to_string())
+            .and_then(|text| storage::save(SETTINGS_KEY, &text));
+        if let Err(e) = saved {
+            warn!("could not save settings: {e}");
+        }
+    }
+}
+
+pub fn toggle_settings_menu(
+    mut commands: Commands,
+    mut time: ResMut<Time<Virtual>>,
+    menu_query: Query<(Entity, &SettingsMenu)>,
+) {
+    if let Ok((entity, menu)) = menu_query.single() {
+        if !menu.was_paused {
+            time.unpause();
+        }
+        commands.entity(entity).despawn();
+        return;
+    }
+    let was_paused = time.is_paused();
+    time.pause();
+    commands
+        .spawn((
+            SettingsMenu { selected: 0, was_paused },
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(0.),
+                right: Val::Percent(0.),
+                top: Val::Percent(0.),
+                bottom: Val::Percent(0.),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        ))
+        .with_children(|builder| {
+            builder.spawn((
+                Text::default(),
+                TextFont::from_font_size(28.0),
+                TextLayout::new_with_justify(JustifyText::Left).with_no_wrap(),
+                TextColor(Color::WHITE),
+                SettingsMenuText,
+            ));
+        });
+}
+
+// up and down pick a row, left and right change it
+pub fn navigate_settings_menu(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut menu_query: Query<&mut SettingsMenu>,
+    mut settings: ResMut<Settings>,
+    sounds: Res<SoundEffects>,
+) {
+    let Ok(mut menu) = menu_query.single_mut() else {
+        return;
+    };
+    if keys.just_pressed(KeyCode::ArrowDown) {
+        menu.selected = (menu.selected + 1) % ROWS;
+    }
+    if keys.just_pressed(KeyCode::ArrowUp) {
+        menu.selected = (menu.selected + ROWS - 1) % ROWS;
+    }
+    let step = if keys.just_pressed(KeyCode::ArrowRight) {
+        VOLUME_STEP
+    } else if keys.just_pressed(KeyCode::ArrowLeft) {
+        -VOLUME_STEP
+    } else {
+        return;
+    };
+
+    let before = settings.clone();
+    let volume = match menu.selected {
+        0 => &mut settings.master_volume,
+        1 => &mut settings.music_volume,
+        2 => &mut settings.sfx_volume,
+        toggle => {
+            if toggle == 3 {
+                settings.camera_effects = !settings.camera_effects;
+            } else {
+                settings.touch_buttons = !settings.touch_buttons;
+            }
+            settings.save();
+            return;
+        }
+    };
+    // rounded so repeated steps land on whole percentages
+    *volume = ((*volume + step) * 10.0).round().clamp(0.0, 10.0) / 10.0;
+    if *settings != before {
+        settings.save();
+        // a sample of the new sound effect volume
+        if menu.selected != 1 {
+            play_sound(&mut commands, &sounds.jump, &settings);
+        }
+    }
+}
+
+pub fn render_settings_menu(
+    settings: Res<Settings>,
+    menu_query: Query<&SettingsMenu>,
+    mut text_query: Query<&mut Text, With<SettingsMenuText>>,
+) {
+    let (Ok(menu), Ok(mut text)) = (menu_query.single(), text_query.single_mut()) else {
+        return;
+    };
+    let percent = |volume: f32| format!("{:>3.0}%", volume * 100.0);
+    let rows = [
+        format!("Master volume   < {} >", percent(settings.master_volume)),
+        format!("Music volume    < {} >", percent(settings.music_volume)),
+        format!("Effects volume  < {} >", percent(settings.sfx_volume)),
+        format!("Camera effects  < {} >", if settings.camera_effects { " on" } else { "off" }),
+        format!("Touch buttons   < {} >", if settings.touch_buttons { " on" } else { "off" }),
+    ];
+    let mut lines = vec!["Settings".to_string(), String::new()];
+    for (i, row) in rows.iter().enumerate() {
+        let cursor = if i == menu.selected { "> " } else { "  " };
+        lines.push(format!("{cursor}{row}"));
+    }
+    lines.push(String::new());
+    lines.push("Esc to close".to_string());
+    text.0 = lines.join("\n");
+}
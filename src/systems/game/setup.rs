@@ -1,20 +1,59 @@
-use crate::components::{AnimationIndices, AnimationTimer, Collider, Health, HealthInfo, Player, PlayerCollider, ScoreInfo, Velocity};
-use crate::constants::{DINO_RUN_IMG_SIZE_X, DINO_RUN_IMG_SIZE_Y, DINO_RUN_SIZE, GROUND_LEVEL, HIT_BOX_SCALE_X, INITIAL_HEALTH, RUN_ANIMATION_TIMER_INTERVAL};
-use crate::resources::{CactusTexture, DinoDuck, DinoDie, DinoJump, DinoRun, HealthPickUpImg, PterodactylDie, PterodactylFly};
+use crate::components::{AnimationIndices, AnimationTimer, Collider, GameCamera, Health, HealthInfo, Player, PlayerCollider, PowerUpInfo, ScoreInfo, Velocity};
+use crate::constants::{DINO_RUN_IMG_SIZE_X, DINO_RUN_IMG_SIZE_Y, DINO_RUN_SIZE, GROUND_LEVEL, HIT_BOX_SCALE_X, INITIAL_HEALTH, PLAYER_X, RUN_ANIMATION_TIMER_INTERVAL, WINDOW_HEIGHT, WINDOW_WIDTH};
+use crate::resources::{CactusTexture, Difficulty, DinoDuck, DinoDie, DinoJump, DinoRun, HealthPickUpImg, PterodactylDie, PterodactylFly};
 use bevy::asset::AssetServer;
 use bevy::image::{TextureAtlas, TextureAtlasLayout};
 use bevy::prelude::*;
+use bevy::render::camera::ScalingMode;
+use bevy::render::view::RenderLayers;
 use bevy::sprite::{Anchor, Sprite};
 
-const PLAYER_X: f32 = -300.0;
+impl Difficulty {
+    pub fn start_speed(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.8,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.3,
+        }
+    }
+
+    pub fn health(&self) -> usize {
+        match self {
+            Difficulty::Easy => INITIAL_HEALTH + 2,
+            Difficulty::Normal | Difficulty::Hard => INITIAL_HEALTH,
+        }
+    }
+}
 
 pub fn setup(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    difficulty: Res<Difficulty>,
     mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
 ) {
 
-    commands.spawn(Camera2d::default());
+    // draws nothing, just clears the letterbox bars around the game camera's viewport
+    commands.spawn((
+        Camera2d,
+        Camera {
+            order: -1,
+            clear_color: ClearColorConfig::Custom(Color::BLACK),
+            ..default()
+        },
+        RenderLayers::layer(1),
+    ));
+    commands.spawn((
+        Camera2d,
+        GameCamera,
+        IsDefaultUiCamera,
+        Projection::Orthographic(OrthographicProjection {
+            scaling_mode: ScalingMode::AutoMin {
+                min_width: WINDOW_WIDTH,
+                min_height: WINDOW_HEIGHT,
+            },
+            ..OrthographicProjection::default_2d()
+        }),
+    ));
     let dino_run = asset_server.load("purple_trex_run.png");
     commands.insert_resource(DinoRun(dino_run.clone()));
     commands.insert_resource(HealthPickUpImg(
@@ -74,12 +113,13 @@ pub fn setup(
                     DINO_RUN_SIZE.y / 2.,
                     0.0,
                 ),
-                Health(INITIAL_HEALTH),
+                Health(difficulty.health()),
             ));
         });
 
 
 
-    commands.spawn((HealthInfo, Text::new(format!("Health: {}", INITIAL_HEALTH))));
+    commands.spawn((HealthInfo, Text::new(format!("Health: {}", difficulty.health()))));
     commands.spawn((ScoreInfo, Text::new(format!("\nScore: {}", 0))));
+    commands.spawn((PowerUpInfo, Text::new("")));
 }
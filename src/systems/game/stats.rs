@@ -0,0 +1,124 @@
+use crate::components::{Cleared, Collider, Obstacle, ObstacleKind, PlayerCollider};
+use crate::events::{NearMiss, ObstacleHit, PickupCollected, PlayerDied, PlayerDucked, PlayerJumped};
+use crate::resources::{BaseSpeed, RunStats};
+use bevy::prelude::*;
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::resources::{Controller, Difficulty, Score};
+#[cfg(not(target_arch = "wasm32"))]
+use serde::Serialize;
+
+// in the config directory next to the settings, one JSON object per run
+#[cfg(not(target_arch = "wasm32"))]
+const RUN_LOG: &str = "runs.jsonl";
+
+#[allow(clippy::too_many_arguments)]
+pub fn track_run_stats(
+    real_time: Res<Time<Real>>,
+    time: Res<Time<Virtual>>,
+    base_speed: Res<BaseSpeed>,
+    mut jumps: EventReader<PlayerJumped>,
+    mut ducks: EventReader<PlayerDucked>,
+    mut hits: EventReader<ObstacleHit>,
+    mut pickups: EventReader<PickupCollected>,
+    mut near_misses: EventReader<NearMiss>,
+    mut deaths: EventReader<PlayerDied>,
+    mut stats: ResMut<RunStats>,
+) {
+    stats.jumps += jumps.read().count() as u32;
+    stats.ducks += ducks.read().count() as u32;
+    stats.hits += hits.read().count() as u32;
+    stats.pickups += pickups.read().count() as u32;
+    stats.near_misses += near_misses.read().count() as u32;
+    if let Some(died) = deaths.read().last() {
+        stats.cause_of_death = Some(died.cause);
+    }
+    // the dying animation isn't play
+    if !time.is_paused() && stats.cause_of_death.is_none() {
+        stats.duration += real_time.delta_secs();
+        stats.max_speed = stats.max_speed.max(base_speed.0);
+    }
+}
+
+// anything that gets past the dino's back without being hit has been cleared, hit colliders are despawned
+pub fn count_cleared_obstacles(
+    mut commands: Commands,
+    player_collider: Query<(&GlobalTransform, &Collider), With<PlayerCollider>>,
+    obstacles: Query<(Entity, &GlobalTransform, &Collider, &Obstacle), Without<Cleared>>,
+    mut stats: ResMut<RunStats>,
+) {
+    let Ok((player_transform, player)) = player_collider.single() else {
+        return;
+    };
+    if stats.cause_of_death.is_some() {
+        return;
+    }
+    let back = player_transform.translation().x - player.size.x / 2.0;
+    for (entity, transform, collider, obstacle) in &obstacles {
+        if transform.translation().x + collider.size.x / 2.0 < back {
+            commands.entity(entity).try_insert(Cleared);
+            *stats.cleared.entry(obstacle.0).or_default() += 1;
+        }
+    }
+}
+
+fn kind_name(kind: ObstacleKind) -> &'static str {
+    match kind {
+        ObstacleKind::Cactus => "cactus",
+        ObstacleKind::Pterodactyl => "pterodactyl",
+        ObstacleKind::Boulder => "boulder",
+        ObstacleKind::LavaPit => "lava pit",
+        ObstacleKind::Meteor => "meteor",
+    }
+}
+
+// a few lines for the game over screen
+pub fn summary(stats: &RunStats) -> String {
+    let mut lines = vec![
+        format!("{:.1}s, top speed x{:.1}", stats.duration, stats.max_speed),
+        [(stats.jumps, "jump"), (stats.ducks, "duck"), (stats.hits, "hit"), (stats.pickups, "pickup")]
+            .map(|(count, what)| format!("{count} {what}{}", if count == 1 { "" } else { "s" }))
+            .join(", "),
+    ];
+    if !stats.cleared.is_empty() {
+        let cleared: Vec<String> = stats.cleared.iter().map(|(kind, count)| format!("{} {count}", kind_name(*kind))).collect();
+        lines.push(format!("Cleared: {}", cleared.join(", ")));
+    }
+    if let Some(cause) = stats.cause_of_death {
+        lines.push(format!("Killed by a {}", kind_name(cause)));
+    }
+    lines.join("\n")
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Serialize)]
+struct RunRecord<'a> {
+    // seconds since 1970
+    ended_at: u64,
+    player: Controller,
+    difficulty: Difficulty,
+    score: u32,
+    #[serde(flatten)]
+    stats: &'a RunStats,
+}
+
+// one line per run, for working out which obstacles and speeds make the game too hard or too easy
+#[cfg(not(target_arch = "wasm32"))]
+pub fn log_run(stats: Res<RunStats>, score: Res<Score>, controller: Res<Controller>, difficulty: Res<Difficulty>) {
+    let record = RunRecord {
+        ended_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs()),
+        player: *controller,
+        difficulty: *difficulty,
+        score: score.total(),
+        stats: &stats,
+    };
+    let logged = serde_json::to_string(&record)
+        .map_err(|err| err.to_string())
+        .and_then(|line| crate::storage::append_line(RUN_LOG, &line));
+    match logged {
+        Ok(path) => debug!("run logged to {}", path.display()),
+        Err(err) => warn!("couldn't log the run: {err}"),
+    }
+}
@@ -0,0 +1,62 @@
+use crate::components::GameCamera;
+use crate::constants::{WINDOW_HEIGHT, WINDOW_WIDTH};
+use crate::resources::VisibleArea;
+use bevy::prelude::*;
+use bevy::render::camera::Viewport;
+use bevy::window::{MonitorSelection, PrimaryWindow, WindowMode};
+
+// taller windows than 4:3 would show below the ground, and wider ones than 8:3 past the edge of the
+// 1920 pixel wide background images, so anything beyond gets black bars
+const MIN_ASPECT: f32 = 4.0 / 3.0;
+const MAX_ASPECT: f32 = 8.0 / 3.0;
+
+// the camera always shows at least the whole 1280x720 playfield, and more of the world on wider
+// or taller windows
+pub fn fit_viewport(
+    windows: Query<&Window, (With<PrimaryWindow>, Changed<Window>)>,
+    mut cameras: Query<&mut Camera, With<GameCamera>>,
+    mut visible: ResMut<VisibleArea>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let size = window.physical_size();
+    if size.x == 0 || size.y == 0 {
+        return;
+    }
+    let aspect = size.x as f32 / size.y as f32;
+    let clamped = aspect.clamp(MIN_ASPECT, MAX_ASPECT);
+
+    let viewport = (clamped != aspect).then(|| {
+        let viewport_size = if aspect > clamped {
+            UVec2::new((size.y as f32 * clamped) as u32, size.y)
+        } else {
+            UVec2::new(size.x, (size.x as f32 / clamped) as u32)
+        };
+        Viewport {
+            physical_position: (size - viewport_size) / 2,
+            physical_size: viewport_size,
+            ..default()
+        }
+    });
+    for mut camera in cameras.iter_mut() {
+        camera.viewport = viewport.clone();
+    }
+
+    let visible_size = if clamped >= WINDOW_WIDTH / WINDOW_HEIGHT {
+        Vec2::new(WINDOW_HEIGHT * clamped, WINDOW_HEIGHT)
+    } else {
+        Vec2::new(WINDOW_WIDTH, WINDOW_WIDTH / clamped)
+    };
+    visible.half_size = visible_size / 2.0;
+}
+
+pub fn toggle_fullscreen(mut windows: Query<&mut Window, With<PrimaryWindow>>) {
+    let Ok(mut window) = windows.single_mut() else {
+        return;
+    };
+    window.mode = match window.mode {
+        WindowMode::Windowed => WindowMode::BorderlessFullscreen(MonitorSelection::Current),
+        _ => WindowMode::Windowed,
+    };
+}
@@ -0,0 +1,95 @@
+use crate::components::{Boulder, BoulderCollider, BoulderRock, Collider, Obstacle, ObstacleKind, Velocity};
+use crate::constants::{GAME_SPEED, GROUND_LEVEL};
+use crate::resources::VisibleArea;
+use crate::systems::obstacles::movement::DESPAWN_MARGIN;
+use bevy::prelude::*;
+use bevy_prng::WyRand;
+use bevy_rand::global::GlobalEntropy;
+use rand::Rng;
+
+// boulders roll towards the player, so they move faster than the ground
+pub const ROLL_SPEED: f32 = 250.0;
+const MIN_RADIUS: f32 = 28.0;
+const MAX_RADIUS: f32 = 48.0;
+const BOUNCE_DAMPING: f32 = 0.4;
+const MIN_BOUNCE_VELOCITY: f32 = 150.0;
+pub const HIT_BOUNCE_VELOCITY: f32 = 1200.0;
+
+pub fn spawn_boulder(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    position: Vec2,
+    rng: &mut GlobalEntropy<WyRand>,
+) {
+    let radius = rng.gen_range(MIN_RADIUS..=MAX_RADIUS);
+
+    commands.spawn((
+        Boulder { radius },
+        Transform::from_xyz(position.x, position.y + radius, 0.0),
+        Visibility::Visible,
+        Velocity(Vec3::ZERO),
+    )).with_children(|boulder| {
+        // the rock rotates, the root does not, so the collider stays axis aligned
+        boulder.spawn((
+            BoulderRock,
+            Mesh2d(meshes.add(RegularPolygon::new(radius, 9))),
+            MeshMaterial2d(materials.add(Color::srgb(0.45, 0.38, 0.33))),
+            Transform::from_xyz(0.0, 0.0, 0.2),
+        )).with_children(|rock| {
+            // a few darker patches so the rolling is visible
+            for (x, y, r) in [(0.35, 0.2, 0.22), (-0.3, -0.25, 0.18), (0.05, -0.5, 0.12)] {
+                rock.spawn((
+                    Mesh2d(meshes.add(Circle::new(radius * r))),
+                    MeshMaterial2d(materials.add(Color::srgb(0.33, 0.28, 0.25))),
+                    Transform::from_xyz(radius * x, radius * y, 0.1),
+                ));
+            }
+        });
+
+        boulder.spawn((
+            BoulderCollider,
+            Obstacle(ObstacleKind::Boulder),
+            Collider {
+                size: Vec2::splat(radius * 1.7),
+            },
+            Transform::IDENTITY,
+        ));
+    });
+}
+
+pub fn roll_boulders(
+    time: Res<Time>,
+    mut commands: Commands,
+    visible: Res<VisibleArea>,
+    mut boulders: Query<(Entity, &Boulder, &mut Transform, &mut Velocity, &Children), Without<BoulderRock>>,
+    mut rocks: Query<&mut Transform, With<BoulderRock>>,
+) {
+    let speed = GAME_SPEED + ROLL_SPEED;
+    for (entity, boulder, mut transform, mut velocity, children) in boulders.iter_mut() {
+        transform.translation.x -= speed * time.delta_secs();
+        transform.translation.y += velocity.0.y * time.delta_secs();
+
+        // bounce off the ground, losing energy each time
+        let floor = GROUND_LEVEL + boulder.radius;
+        if transform.translation.y <= floor {
+            transform.translation.y = floor;
+            velocity.0.y = if velocity.0.y < -MIN_BOUNCE_VELOCITY {
+                -velocity.0.y * BOUNCE_DAMPING
+            } else {
+                0.0
+            };
+        }
+
+        for &child in children {
+            if let Ok(mut rock) = rocks.get_mut(child) {
+                // roll without slipping relative to the ground
+                rock.rotate_z(ROLL_SPEED / boulder.radius * time.delta_secs());
+            }
+        }
+
+        if transform.translation.x < -visible.half_size.x - DESPAWN_MARGIN {
+            commands.entity(entity).try_despawn();
+        }
+    }
+}
@@ -1,140 +1,450 @@
-use crate::components::{CactusArm, CactusCollider, CactusRoot, Collider, IsHit, Velocity};
+use crate::components::{CactusArm, CactusCollider, CactusRoot, Collider, IsHit, Obstacle, ObstacleKind, Velocity};
 use crate::resources::CactusTexture;
 use bevy::asset::Assets;
 use bevy::color::Color;
-use bevy::math::{Quat, Vec2};
+use bevy::math::{Quat, Rect, Vec2};
 use bevy::prelude::*;
 use bevy_prng::WyRand;
 use bevy_rand::global::GlobalEntropy;
 use rand::Rng;
-use rand_core::RngCore;
-use std::f32::consts::PI;
+use rand_core::{RngCore, SeedableRng};
+use std::f32::consts::{PI, TAU};
 
-const CACTUS_FLOWER_CHANCE: f32 = 0.3; // 30% chance to spawn a flower on top of cactus
+pub const MAX_SAGUARO_ARMS: usize = 3;
+pub const MAX_PEAR_PADS: usize = 3;
+// the collider is a little smaller than the drawn cactus, so grazing a spine doesn't hurt
+const COLLIDER_INSET: Vec2 = Vec2::new(6.0, 10.0);
+const PAD_TILT: f32 = 0.35;
+const FLOWER_SPIKES: usize = 3;
+const FLOWER_SPIKE_SIZE: Vec2 = Vec2::new(1.5, 8.0);
+const FLOWER_COLORS: [Color; 5] = [
+    Color::WHITE,
+    Color::srgb(1.0, 0.45, 0.7),
+    Color::srgb(1.0, 0.85, 0.2),
+    Color::srgb(0.95, 0.2, 0.2),
+    Color::srgb(1.0, 0.55, 0.1),
+];
 
-pub fn spawn_cactus(
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
-    cactus_texture: Res<CactusTexture>,
-    position: Vec2,
-    rng: &mut GlobalEntropy<WyRand>,
-) {
-    // cactus parameters
-    let max_trunk_width = 30.0;
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CactusSpecies {
+    Saguaro,
+    Barrel,
+    PricklyPear,
+    Cluster,
+}
+
+// everything that makes one cactus look the way it does, in pixels from the middle of its base
+#[derive(Clone, Debug, PartialEq)]
+pub struct CactusSpec {
+    pub species: CactusSpecies,
+    // upright parts standing on the ground
+    pub stems: Vec<Stem>,
+    // parts that get knocked off when the dino runs into the cactus
+    pub arms: Vec<Arm>,
+    pub flower: Option<Color>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stem {
+    pub x: f32,
+    pub size: Vec2,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Arm {
+    // where the arm joins its stem
+    pub anchor: Vec2,
+    // 1 for right, -1 for left
+    pub side: f32,
+    // how far out and up a saguaro arm reaches, or the width and height of a pad
+    pub size: Vec2,
+    pub thickness: f32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PartShape {
+    Rectangle(Vec2),
+    Circle(f32),
+    Ellipse(Vec2),
+    Capsule { radius: f32, length: f32 },
+    Sector { radius: f32, angle: f32 },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PartPaint {
+    Flesh,
+    Flower(Color),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CactusPart {
+    pub shape: PartShape,
+    pub paint: PartPaint,
+    pub translation: Vec3,
+    pub rotation: f32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArmParts {
+    pub anchor: Vec2,
+    // relative to the anchor, so the arm can fall on its own
+    pub parts: Vec<CactusPart>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CactusParts {
+    pub body: Vec<CactusPart>,
+    pub arms: Vec<ArmParts>,
+    pub collider: Rect,
+}
+
+impl CactusSpec {
+    pub fn from_seed(seed: u64) -> Self {
+        let mut rng = WyRand::seed_from_u64(seed);
+        let species = match rng.gen_range(0..100) {
+            0..50 => CactusSpecies::Saguaro,
+            50..65 => CactusSpecies::Barrel,
+            65..85 => CactusSpecies::PricklyPear,
+            _ => CactusSpecies::Cluster,
+        };
+        let (stems, arms, flower_chance) = match species {
+            CactusSpecies::Saguaro => saguaro(&mut rng),
+            CactusSpecies::Barrel => barrel(&mut rng),
+            CactusSpecies::PricklyPear => prickly_pear(&mut rng),
+            CactusSpecies::Cluster => cluster(&mut rng),
+        };
+        let flower = rng.gen_bool(flower_chance)
+            .then(|| FLOWER_COLORS[rng.gen_range(0..FLOWER_COLORS.len())]);
+        CactusSpec { species, stems, arms, flower }
+    }
+}
+
+fn saguaro(rng: &mut WyRand) -> (Vec<Stem>, Vec<Arm>, f64) {
     let min_trunk_width = 23.0;
-    let trunk_height = rng.gen_range(58..158) as f32;
-    let min_arm_highness = 20.0;
-    let max_arm_highness = trunk_height - 10.0;
+    let max_trunk_width = 30.0;
     let min_arm_width = 15.0;
     let max_arm_width = 30.0;
+    let trunk_height = rng.gen_range(58..158) as f32;
     let trunk_width = rng.gen_range(min_trunk_width..=max_trunk_width);
-    let arm_length = trunk_width / 2.0;
+    let thickness = trunk_width / 2.0;
+    // thicker trunks grow longer arms
     let scale = (trunk_width - min_trunk_width) / (max_trunk_width - min_trunk_width);
     let arm_width = min_arm_width + scale * (max_arm_width - min_arm_width);
 
-    // flower parameters
-    let top_spike_count = 3;
-    let spike_length = 8.0;
-    let spike_width = 1.5;
-    let flower = rng.next_u32() % 100 < (CACTUS_FLOWER_CHANCE * 100.0) as u32;
+    let mut side = if rng.gen_bool(0.5) { 1.0 } else { -1.0 };
+    let arms = (0..rng.gen_range(0..=MAX_SAGUARO_ARMS))
+        .map(|_| {
+            let height = rng.gen_range(20.0..=trunk_height - 10.0);
+            let length = (thickness * rng.gen_range(1..=3) as f32).min(trunk_height - height);
+            side = -side;
+            Arm {
+                anchor: Vec2::new(10.0 * side, height),
+                side,
+                size: Vec2::new(arm_width, length),
+                thickness,
+            }
+        })
+        .collect();
+    (vec![Stem { x: 0.0, size: Vec2::new(trunk_width, trunk_height) }], arms, 0.3)
+}
 
+fn barrel(rng: &mut WyRand) -> (Vec<Stem>, Vec<Arm>, f64) {
+    let size = Vec2::new(rng.gen_range(40.0..60.0), rng.gen_range(36.0..64.0));
+    (vec![Stem { x: 0.0, size }], Vec::new(), 0.6)
+}
 
-    commands.spawn((CactusRoot,
-                    Transform::from_xyz(position.x, position.y, 0.0),
-                    Visibility::Visible)).with_children(|root| {
-        // Circle top
-        let circle_radius = trunk_width / 2.0;
-        root.spawn((
-            Mesh2d(meshes.add(Circle::new(circle_radius)).into()),
-            MeshMaterial2d(materials.add(cactus_texture.0.clone())),
-            Transform::from_xyz(0.0, trunk_height, 0.1),
-        ));
-
-        // Top flower
-        if flower {
-            for i in 0..top_spike_count {
-                let angle = PI + (i as f32 * std::f32::consts::TAU / top_spike_count as f32);
-
-                root.spawn((
-                    Mesh2d(meshes.add(Rectangle::new(spike_width, spike_length)).into()),
-                    MeshMaterial2d(materials.add(Color::WHITE)),
-                    Transform::from_xyz(0.0, trunk_height + circle_radius, 0.5)
-                        .with_rotation(Quat::from_rotation_z(angle)),
-                ));
+fn prickly_pear(rng: &mut WyRand) -> (Vec<Stem>, Vec<Arm>, f64) {
+    let base = Vec2::new(rng.gen_range(34.0..46.0), rng.gen_range(44.0..60.0));
+    let arms = (0..rng.gen_range(1..=MAX_PEAR_PADS))
+        .map(|_| {
+            let x = rng.gen_range(-base.x / 3.0..base.x / 3.0);
+            Arm {
+                anchor: Vec2::new(x, base.y * 0.85),
+                side: if x < 0.0 { -1.0 } else { 1.0 },
+                size: Vec2::new(rng.gen_range(26.0..38.0), rng.gen_range(34.0..48.0)),
+                thickness: 0.0,
+            }
+        })
+        .collect();
+    (vec![Stem { x: 0.0, size: base }], arms, 0.5)
+}
+
+fn cluster(rng: &mut WyRand) -> (Vec<Stem>, Vec<Arm>, f64) {
+    let mut stems: Vec<Stem> = Vec::new();
+    let mut right = 0.0;
+    for _ in 0..rng.gen_range(2..=4) {
+        let size = Vec2::new(rng.gen_range(14.0..20.0), rng.gen_range(30.0..90.0));
+        let gap = if stems.is_empty() { 0.0 } else { rng.gen_range(2.0..6.0) };
+        stems.push(Stem { x: right + gap + size.x / 2.0, size });
+        right += gap + size.x;
+    }
+    // centre the group on the spawn point
+    for stem in stems.iter_mut() {
+        stem.x -= right / 2.0;
+    }
+    (stems, Vec::new(), 0.3)
+}
+
+fn flesh(shape: PartShape, translation: Vec3) -> CactusPart {
+    CactusPart { shape, paint: PartPaint::Flesh, translation, rotation: 0.0 }
+}
+
+// a little star of spikes
+fn flower(color: Option<Color>, at: Vec2, parts: &mut Vec<CactusPart>) {
+    let Some(color) = color else {
+        return;
+    };
+    for i in 0..FLOWER_SPIKES {
+        parts.push(CactusPart {
+            shape: PartShape::Rectangle(FLOWER_SPIKE_SIZE),
+            paint: PartPaint::Flower(color),
+            translation: at.extend(0.5),
+            rotation: PI + i as f32 * TAU / FLOWER_SPIKES as f32,
+        });
+    }
+}
+
+// the meshes that draw a cactus and the collider that matches them
+pub fn cactus_parts(spec: &CactusSpec) -> CactusParts {
+    let mut body = Vec::new();
+    for stem in &spec.stems {
+        let Vec2 { x: width, y: height } = stem.size;
+        match spec.species {
+            CactusSpecies::Saguaro | CactusSpecies::Cluster => {
+                let radius = width / 2.0;
+                body.push(flesh(PartShape::Rectangle(stem.size), Vec3::new(stem.x, height / 2.0, 0.6)));
+                body.push(flesh(PartShape::Circle(radius), Vec3::new(stem.x, height, 0.1)));
+                flower(spec.flower, Vec2::new(stem.x, height + radius), &mut body);
+            }
+            CactusSpecies::Barrel => {
+                // round on top, flat where it sits on the ground
+                body.push(flesh(PartShape::Ellipse(stem.size / 2.0), Vec3::new(stem.x, height / 2.0, 0.6)));
+                body.push(flesh(PartShape::Rectangle(Vec2::new(width * 0.8, height / 2.0)), Vec3::new(stem.x, height / 4.0, 0.5)));
+                for x in [-0.25, 0.0, 0.25] {
+                    flower(spec.flower, Vec2::new(stem.x + width * x, height * (1.0 - x.abs() * 0.6)), &mut body);
+                }
+            }
+            CactusSpecies::PricklyPear => {
+                body.push(flesh(PartShape::Ellipse(stem.size / 2.0), Vec3::new(stem.x, height / 2.0, 0.6)));
             }
         }
+    }
+
+    let arms: Vec<ArmParts> = spec.arms.iter().map(|arm| ArmParts { anchor: arm.anchor, parts: arm_parts(spec, arm) }).collect();
+    let collider = collider(&body, &arms);
+    CactusParts { body, arms, collider }
+}
+
+fn arm_parts(spec: &CactusSpec, arm: &Arm) -> Vec<CactusPart> {
+    let side = arm.side;
+    let mut parts = Vec::new();
+    if spec.species == CactusSpecies::PricklyPear {
+        // pads lean outwards from where they grow
+        let rotation = -side * PAD_TILT;
+        let up = Vec2::new(-rotation.sin(), rotation.cos());
+        parts.push(CactusPart {
+            rotation,
+            ..flesh(PartShape::Ellipse(arm.size / 2.0), (up * arm.size.y * 0.45).extend(0.4))
+        });
+        flower(spec.flower, up * arm.size.y * 0.9, &mut parts);
+        return parts;
+    }
+
+    // a horizontal stub, a curve, then a capsule growing upwards
+    let curve_radius = arm.thickness;
+    let Vec2 { x: reach, y: length } = arm.size;
+    let stub_width = reach - curve_radius;
+    parts.push(flesh(PartShape::Rectangle(Vec2::new(stub_width, arm.thickness)), Vec3::new(side * stub_width / 2.0, 0.0, 0.2)));
+    parts.push(CactusPart {
+        rotation: side * PI,
+        ..flesh(PartShape::Sector { radius: curve_radius, angle: PI / 4.0 }, Vec3::new(side * stub_width, arm.thickness / 2.0, 0.3))
+    });
+    let tip_x = side * (reach - curve_radius / 2.0);
+    parts.push(flesh(PartShape::Capsule { radius: curve_radius / 2.0, length }, Vec3::new(tip_x, length / 2.0, 0.4)));
+    flower(spec.flower, Vec2::new(tip_x, length + curve_radius / 2.0), &mut parts);
+    parts
+}
+
+impl PartShape {
+    fn half_size(&self) -> Vec2 {
+        match *self {
+            PartShape::Rectangle(size) => size / 2.0,
+            PartShape::Circle(radius) => Vec2::splat(radius),
+            PartShape::Ellipse(half_size) => half_size,
+            PartShape::Capsule { radius, length } => Vec2::new(radius, length / 2.0 + radius),
+            // the whole circle, to keep it simple
+            PartShape::Sector { radius, .. } => Vec2::splat(radius),
+        }
+    }
+}
+
+impl CactusPart {
+    // axis aligned bounds of the part, offset by where its parent sits
+    fn bounds(&self, offset: Vec2) -> Rect {
+        let half = self.shape.half_size();
+        let (sin, cos) = self.rotation.sin_cos();
+        let rotated = Vec2::new(half.x * cos.abs() + half.y * sin.abs(), half.x * sin.abs() + half.y * cos.abs());
+        Rect::from_center_half_size(offset + self.translation.truncate(), rotated)
+    }
+}
 
-        // Main trunk
-        root.spawn((
-            Mesh2d(meshes.add(Rectangle::new(trunk_width, trunk_height)).into()),
-            MeshMaterial2d(materials.add(cactus_texture.0.clone())),
-            Transform::from_xyz(0.0, trunk_height / 2.0, 0.6),
+// the drawn flesh, minus a little forgiveness on the sides and the top
+fn collider(body: &[CactusPart], arms: &[ArmParts]) -> Rect {
+    let flesh_bounds = body
+        .iter()
+        .map(|part| (part, Vec2::ZERO))
+        .chain(arms.iter().flat_map(|arm| arm.parts.iter().map(|part| (part, arm.anchor))))
+        .filter(|(part, _)| part.paint == PartPaint::Flesh)
+        .map(|(part, offset)| part.bounds(offset))
+        .reduce(|a, b| a.union(b))
+        .unwrap_or_default();
+    let mut collider = flesh_bounds;
+    collider.min.x += COLLIDER_INSET.x;
+    collider.max.x -= COLLIDER_INSET.x;
+    collider.max.y -= COLLIDER_INSET.y;
+    collider.min.y = collider.min.y.max(0.0);
+    collider
+}
+
+fn mesh_for(shape: PartShape, meshes: &mut Assets<Mesh>) -> Handle<Mesh> {
+    match shape {
+        PartShape::Rectangle(size) => meshes.add(Rectangle::from_size(size)),
+        PartShape::Circle(radius) => meshes.add(Circle::new(radius)),
+        PartShape::Ellipse(half_size) => meshes.add(Ellipse::new(half_size.x, half_size.y)),
+        PartShape::Capsule { radius, length } => meshes.add(Capsule2d::new(radius, length)),
+        PartShape::Sector { radius, angle } => meshes.add(CircularSector::from_radians(radius, angle)),
+    }
+}
 
-        )).with_children(|trunk| {
+pub fn spawn_cactus(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    cactus_texture: Res<CactusTexture>,
+    position: Vec2,
+    rng: &mut GlobalEntropy<WyRand>,
+) {
+    let parts = cactus_parts(&CactusSpec::from_seed(rng.next_u64()));
+    let flesh = materials.add(cactus_texture.0.clone());
+    let mut part_bundle = |part: &CactusPart| {
+        let material = match part.paint {
+            PartPaint::Flesh => flesh.clone(),
+            PartPaint::Flower(color) => materials.add(color),
+        };
+        (
+            Mesh2d(mesh_for(part.shape, &mut meshes)),
+            MeshMaterial2d(material),
+            Transform::from_translation(part.translation).with_rotation(Quat::from_rotation_z(part.rotation)),
+        )
+    };
+
+    commands.spawn((CactusRoot,
+                    Transform::from_xyz(position.x, position.y, 0.0),
+                    Visibility::Visible)).with_children(|root| {
+        root.spawn((Transform::IDENTITY, Visibility::Visible)).with_children(|body| {
+            for part in &parts.body {
+                body.spawn(part_bundle(part));
+            }
             // cactus collider needs to be leaf node (for despawning),
             // and only 1 per cactus (for efficiency and single health point deduction),
             // and close to cactus arm (for convenience)
-            // so it's the cactus arm sibling. both are children of the trunk.
-            trunk.spawn((
+            // so it's the cactus arm sibling. both are children of the body.
+            body.spawn((
                 CactusCollider,
-                Transform::IDENTITY,
-                Collider{size: Vec2::new(trunk_width + 2.0 * arm_width - arm_length / 2.0, trunk_height)},
+                Obstacle(ObstacleKind::Cactus),
+                Transform::from_translation(parts.collider.center().extend(0.0)),
+                Collider { size: parts.collider.size() },
             ));
-
-            // Generate cactus arms
-            let x_multi = [1.0, -1.0];
-            let curve_radius = arm_length;
-            for i in 0..2 {
-                let arm_highness = rng.gen_range(min_arm_highness..=max_arm_highness);
-                let caps_length = (curve_radius * ((rng.next_u32() % 3 + 1) as f32)).min(trunk_height - arm_highness);
-
-                trunk.spawn((
+            for arm in &parts.arms {
+                body.spawn((
                     CactusArm,
                     IsHit(false),
-                    Transform::from_xyz(10.0 * x_multi[i], arm_highness-trunk_height / 2.0, -0.6),  // offset the transform of the trunk
+                    Transform::from_translation(arm.anchor.extend(0.0)),
                     Visibility::Visible,
                     Velocity(Vec3::ZERO),
-                )).with_children(|arm: &mut ChildSpawnerCommands| {
-
-                    // Horizontal side arm
-                    let rect_width = arm_width - curve_radius;
-                    arm.spawn((
-                        Mesh2d(meshes.add(Rectangle::new(rect_width, arm_length)).into()),
-                        MeshMaterial2d(materials.add(cactus_texture.0.clone())),
-                        Transform::from_xyz(x_multi[i] * (rect_width / 2.0), 0., 0.2),
-                    ));
-
-                    // Curved segment to add texture noise between the horizontal and vertical segments
-                    arm.spawn((
-                        Mesh2d(meshes.add(CircularSector::from_radians(curve_radius, PI / 4.0)).into()),
-                        MeshMaterial2d(materials.add(cactus_texture.0.clone())),
-                        Transform::from_xyz(x_multi[i] * (arm_width - curve_radius), arm_length / 2.0, 0.3)
-                            .with_rotation(Quat::from_rotation_z(x_multi[i] * PI)),
-                    ));
-
-                    // Vertical capsule
-                    arm.spawn((
-                        Mesh2d(meshes.add(Capsule2d::new(curve_radius / 2.0, caps_length)).into()),
-                        MeshMaterial2d(materials.add(cactus_texture.0.clone())),
-                        Transform::from_xyz(x_multi[i] * (arm_width - curve_radius / 2.0), caps_length / 2.0, 0.4),
-                    ));
-
-                    // Side arm flowers
-                    if flower {
-                        for j in 0..top_spike_count {
-                            let angle = PI + (j as f32 * std::f32::consts::TAU / top_spike_count as f32);
-
-                            arm.spawn((
-                                Mesh2d(meshes.add(Rectangle::new(spike_width, spike_length)).into()),
-                                MeshMaterial2d(materials.add(Color::WHITE)),
-                                Transform::from_xyz(x_multi[i] * (arm_width - curve_radius / 2.0), caps_length + curve_radius / 2.0, 0.5)
-                                    .with_rotation(Quat::from_rotation_z(angle)),
-                            ));
-                        }
-                    };
+                )).with_children(|arm_parts| {
+                    for part in &arm.parts {
+                        arm_parts.spawn(part_bundle(part));
+                    }
                 });
             }
         });
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn specs() -> impl Iterator<Item = CactusSpec> {
+        (0..2000).map(CactusSpec::from_seed)
+    }
+
+    #[test]
+    fn same_seed_same_cactus() {
+        assert_eq!(CactusSpec::from_seed(42), CactusSpec::from_seed(42));
+        assert_eq!(cactus_parts(&CactusSpec::from_seed(42)), cactus_parts(&CactusSpec::from_seed(42)));
+    }
+
+    #[test]
+    fn every_species_and_arm_count_shows_up() {
+        for species in [CactusSpecies::Saguaro, CactusSpecies::Barrel, CactusSpecies::PricklyPear, CactusSpecies::Cluster] {
+            assert!(specs().any(|spec| spec.species == species), "{species:?} never generated");
+        }
+        for arms in 0..=MAX_SAGUARO_ARMS {
+            assert!(specs().any(|spec| spec.species == CactusSpecies::Saguaro && spec.arms.len() == arms));
+        }
+        assert!(specs().any(|spec| spec.flower.is_some()));
+        assert!(specs().any(|spec| spec.flower.is_none()));
+    }
+
+    #[test]
+    fn arm_counts_stay_in_range() {
+        for spec in specs() {
+            let max_arms = match spec.species {
+                CactusSpecies::Saguaro => MAX_SAGUARO_ARMS,
+                CactusSpecies::PricklyPear => MAX_PEAR_PADS,
+                CactusSpecies::Barrel | CactusSpecies::Cluster => 0,
+            };
+            assert!(spec.arms.len() <= max_arms, "{spec:?}");
+            let parts = cactus_parts(&spec);
+            assert_eq!(parts.arms.len(), spec.arms.len());
+        }
+    }
+
+    #[test]
+    fn collider_stands_on_the_ground_inside_the_cactus() {
+        for spec in specs() {
+            let parts = cactus_parts(&spec);
+            let collider = parts.collider;
+            assert_eq!(collider.min.y, 0.0, "{spec:?}");
+            assert!(collider.width() > 0.0 && collider.height() > 0.0, "{spec:?}");
+            // every stem is solid in the middle
+            for stem in &spec.stems {
+                assert!(collider.contains(Vec2::new(stem.x, stem.size.y / 2.0)), "{spec:?}");
+            }
+            let tallest = spec.stems.iter().map(|stem| stem.size.y).fold(0.0, f32::max);
+            assert!(collider.max.y >= tallest - COLLIDER_INSET.y, "{spec:?}");
+        }
+    }
+
+    #[test]
+    fn collider_widens_with_arms() {
+        let spec = CactusSpec {
+            species: CactusSpecies::Saguaro,
+            stems: vec![Stem { x: 0.0, size: Vec2::new(24.0, 100.0) }],
+            arms: Vec::new(),
+            flower: None,
+        };
+        let bare = cactus_parts(&spec).collider;
+        assert_eq!(bare.width(), 24.0 - 2.0 * COLLIDER_INSET.x);
+
+        let armed = CactusSpec {
+            arms: vec![Arm { anchor: Vec2::new(10.0, 40.0), side: 1.0, size: Vec2::new(20.0, 24.0), thickness: 12.0 }],
+            ..spec
+        };
+        let armed = cactus_parts(&armed).collider;
+        assert_eq!(armed.min.x, bare.min.x);
+        assert!(armed.max.x > bare.max.x + 15.0);
+    }
+}
@@ -1,66 +1,36 @@
 use bevy::color::palettes::basic::{BLUE, RED};
 use bevy::prelude::*;
 
-use crate::components::{AnimationIndices, CactusArm, CactusCollider, Collider, Health, HealthPickup, IsHit, PlayerCollider, PterodactylCollider, Velocity};
-use crate::constants::{GROUND_LEVEL, PTERO_SIZE_X, PTERO_SIZE_Y};
-use crate::resources::PterodactylDie;
+use crate::components::{Collider, Hurt, Obstacle, Player, PlayerCollider, PowerUp};
+use crate::constants::GROUND_LEVEL;
+use crate::events::{ObstacleHit, PickupCollected};
 
+#[allow(clippy::type_complexity)]
 pub fn detect_collision(
-    mut commands: Commands,
-    mut player_collider_query: Query<(&GlobalTransform, &Collider, &mut Health), With<PlayerCollider>>,
-    collider_query: Query<(&GlobalTransform, &Collider, Entity), Or<(With<CactusCollider>, With<HealthPickup>, With<PterodactylCollider>)>>,
-
-    mut cactus_collider: Query<&ChildOf, With<CactusCollider>>,
-    mut children_query: Query<&Children>,
-    mut cactus_arm_query: Query<(&mut IsHit, &mut Velocity), With<CactusArm>>,
-    mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
-
-    mut pterodactyl_parent_query: Query<&ChildOf, With<PterodactylCollider>>,
-    mut pterodactyl_query: Query<(&mut Sprite, &mut AnimationIndices, &mut IsHit, &mut Velocity), Without<CactusArm>>,
-    pterodactyl_die: Res<PterodactylDie>,
+    player_collider_query: Query<(&GlobalTransform, &Collider), With<PlayerCollider>>,
+    collider_query: Query<(&GlobalTransform, &Collider, Entity, Option<&Obstacle>, Option<&PowerUp>), Or<(With<Obstacle>, With<PowerUp>)>>,
+    hurt_query: Query<(), (With<Player>, With<Hurt>)>,
+    mut obstacle_hits: EventWriter<ObstacleHit>,
+    mut pickups: EventWriter<PickupCollected>,
 ) {
-    // get player's health and collider
-    let (player_transform, player_collider, mut health) =  player_collider_query.single_mut().unwrap();
+    // get player's collider
+    let (player_transform, player_collider) = player_collider_query.single().unwrap();
     let player_half = player_collider.size / 2.0;
+    // obstacles pass through a recovering dino, pickups are still collected
+    let invulnerable = !hurt_query.is_empty();
 
     // query for colliders
-    for (transform, collider, entity) in collider_query.iter() {
-        if is_colliding(player_transform.translation(), player_half, transform.translation(), collider.size / 2.0) {
-            // cactus collision
-            if let Ok(parent) = cactus_collider.get_mut(entity) {
-                // get collider parent's children, aka siblings, which includes the cactus arm
-                let children = children_query.get_mut(parent.0).unwrap();
-                // reset cactus arm velocity to 0
-                for &child in children {
-                    if let Ok((mut is_hit, mut velocity)) = cactus_arm_query.get_mut(child) {
-                        is_hit.0 = true;
-                        velocity.0.y = 0.;
-                    }
-                }
-                health.0 = health.0.saturating_sub(1);
-
-            // pterodactyl collision
-            } else if let Ok(parent) = pterodactyl_parent_query.get_mut(entity) {
-                let (mut ptero_sprite, mut anim_indices, mut is_hit, mut velocity) = pterodactyl_query.get_mut(parent.0).unwrap();
-                // change animation to die
-                let layout = TextureAtlasLayout::from_grid(UVec2::new(PTERO_SIZE_X, PTERO_SIZE_Y), 4, 1, None, None);
-                let texture_atlas_layout = texture_atlas_layouts.add(layout);
-                ptero_sprite.image = pterodactyl_die.0.clone();
-                ptero_sprite.texture_atlas = Some(TextureAtlas{
-                    layout: texture_atlas_layout,
-                    index: 0,
-                });
-                anim_indices.last = 3;
-                is_hit.0 = true;
-                velocity.0.y = 0.;
-                health.0 = health.0.saturating_sub(1);
-
-            //  health pickup collision
-            } else {
-                health.0 = health.0.saturating_add(1);
+    for (transform, collider, entity, obstacle, power_up) in collider_query.iter() {
+        if !is_colliding(player_transform.translation(), player_half, transform.translation(), collider.size / 2.0) {
+            continue;
+        }
+        // the reactions, including despawning the collider, live in the event subscribers
+        if let Some(obstacle) = obstacle {
+            if !invulnerable {
+                obstacle_hits.write(ObstacleHit { kind: obstacle.0, entity });
             }
-            // despawn collider after collision
-            commands.entity(entity).despawn();
+        } else if let Some(power_up) = power_up {
+            pickups.write(PickupCollected { kind: power_up.0, entity });
         }
     }
 }
@@ -78,7 +48,7 @@ pub fn debug_outlines(
 {
     const LENGTH: f32 = 999999.0;
     gizmos.line(
-        Vec3::new(-1. * LENGTH / 2.0, GROUND_LEVEL, 10.0), // Start point
+        Vec3::new(-LENGTH / 2.0, GROUND_LEVEL, 10.0), // Start point
         Vec3::new(LENGTH / 2.0, GROUND_LEVEL, 10.0), // End point
         BLUE,
     );
@@ -0,0 +1,83 @@
+use crate::components::{AnimationIndices, CactusArm, IsHit, ObstacleKind, Velocity};
+use crate::constants::{PTERO_SIZE_X, PTERO_SIZE_Y};
+use crate::events::{ObstacleHit, PickupCollected};
+use crate::resources::PterodactylDie;
+use crate::systems::obstacles::boulder::HIT_BOUNCE_VELOCITY;
+use bevy::prelude::*;
+
+pub fn knock_off_cactus_arms(
+    mut events: EventReader<ObstacleHit>,
+    parent_query: Query<&ChildOf>,
+    children_query: Query<&Children>,
+    mut cactus_arm_query: Query<(&mut IsHit, &mut Velocity), With<CactusArm>>,
+) {
+    for hit in events.read().filter(|hit| hit.kind == ObstacleKind::Cactus) {
+        let Ok(parent) = parent_query.get(hit.entity) else {
+            continue;
+        };
+        // get collider parent's children, aka siblings, which includes the cactus arm
+        let Ok(children) = children_query.get(parent.0) else {
+            continue;
+        };
+        // reset cactus arm velocity to 0
+        for &child in children {
+            if let Ok((mut is_hit, mut velocity)) = cactus_arm_query.get_mut(child) {
+                is_hit.0 = true;
+                velocity.0.y = 0.;
+            }
+        }
+    }
+}
+
+pub fn knock_down_pterodactyl(
+    mut events: EventReader<ObstacleHit>,
+    parent_query: Query<&ChildOf>,
+    mut pterodactyl_query: Query<(&mut Sprite, &mut AnimationIndices, &mut IsHit, &mut Velocity), Without<CactusArm>>,
+    mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+    pterodactyl_die: Res<PterodactylDie>,
+) {
+    for hit in events.read().filter(|hit| hit.kind == ObstacleKind::Pterodactyl) {
+        let Ok(parent) = parent_query.get(hit.entity) else {
+            continue;
+        };
+        let Ok((mut ptero_sprite, mut anim_indices, mut is_hit, mut velocity)) = pterodactyl_query.get_mut(parent.0) else {
+            continue;
+        };
+        // change animation to die
+        let layout = TextureAtlasLayout::from_grid(UVec2::new(PTERO_SIZE_X, PTERO_SIZE_Y), 4, 1, None, None);
+        let texture_atlas_layout = texture_atlas_layouts.add(layout);
+        ptero_sprite.image = pterodactyl_die.0.clone();
+        ptero_sprite.texture_atlas = Some(TextureAtlas{
+            layout: texture_atlas_layout,
+            index: 0,
+        });
+        anim_indices.last = 3;
+        is_hit.0 = true;
+        velocity.0.y = 0.;
+    }
+}
+
+pub fn bounce_boulder(
+    mut events: EventReader<ObstacleHit>,
+    parent_query: Query<&ChildOf>,
+    mut velocity_query: Query<&mut Velocity>,
+) {
+    for hit in events.read().filter(|hit| hit.kind == ObstacleKind::Boulder) {
+        if let Ok(parent) = parent_query.get(hit.entity)
+            && let Ok(mut velocity) = velocity_query.get_mut(parent.0)
+        {
+            velocity.0.y = HIT_BOUNCE_VELOCITY;
+        }
+    }
+}
+
+// runs after the other subscribers, which may still need the collider's parent
+pub fn despawn_hit_colliders(
+    mut commands: Commands,
+    mut obstacle_hits: EventReader<ObstacleHit>,
+    mut pickups: EventReader<PickupCollected>,
+) {
+    for entity in obstacle_hits.read().map(|hit| hit.entity).chain(pickups.read().map(|pickup| pickup.entity)) {
+        commands.entity(entity).try_despawn();
+    }
+}
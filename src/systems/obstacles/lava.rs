@@ -0,0 +1,57 @@
+use crate::components::{Collider, LavaPit, LavaPitCollider, Obstacle, ObstacleKind};
+use bevy::prelude::*;
+use bevy_prng::WyRand;
+use bevy_rand::global::GlobalEntropy;
+use rand::Rng;
+
+const MIN_PIT_WIDTH: f32 = 90.0;
+const MAX_PIT_WIDTH: f32 = 150.0;
+const PIT_DEPTH: f32 = 60.0;
+// only the middle of the pit is deadly, so grazing the rim with a heel is forgiven
+const PIT_COLLIDER_SCALE_X: f32 = 0.5;
+// tall enough to catch a landing dino, short enough to be cleared by any jump
+const PIT_COLLIDER_HEIGHT: f32 = 20.0;
+
+pub fn spawn_lava_pit(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    position: Vec2,
+    rng: &mut GlobalEntropy<WyRand>,
+) {
+    let width = rng.gen_range(MIN_PIT_WIDTH..=MAX_PIT_WIDTH);
+
+    commands.spawn((
+        LavaPit,
+        Transform::from_xyz(position.x, position.y, -0.5),
+        Visibility::Visible,
+    )).with_children(|pit| {
+        // dark rim
+        pit.spawn((
+            Mesh2d(meshes.add(Rectangle::new(width + 12.0, PIT_DEPTH + 6.0))),
+            MeshMaterial2d(materials.add(Color::srgb(0.2, 0.1, 0.08))),
+            Transform::from_xyz(0.0, -(PIT_DEPTH + 6.0) / 2.0, 0.0),
+        ));
+        // molten lava
+        pit.spawn((
+            Mesh2d(meshes.add(Rectangle::new(width, PIT_DEPTH))),
+            MeshMaterial2d(materials.add(Color::srgb(0.95, 0.3, 0.05))),
+            Transform::from_xyz(0.0, -PIT_DEPTH / 2.0, 0.1),
+        ));
+        // glowing surface
+        pit.spawn((
+            Mesh2d(meshes.add(Rectangle::new(width, 8.0))),
+            MeshMaterial2d(materials.add(Color::srgb(1.0, 0.8, 0.2))),
+            Transform::from_xyz(0.0, -4.0, 0.2),
+        ));
+
+        pit.spawn((
+            LavaPitCollider,
+            Obstacle(ObstacleKind::LavaPit),
+            Collider {
+                size: Vec2::new(width * PIT_COLLIDER_SCALE_X, PIT_COLLIDER_HEIGHT),
+            },
+            Transform::IDENTITY,
+        ));
+    });
+}
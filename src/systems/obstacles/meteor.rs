@@ -0,0 +1,88 @@
+use crate::components::{Collider, Meteor, MeteorShadow, Obstacle, ObstacleKind};
+use bevy::prelude::*;
+use std::f32::consts::PI;
+
+// how long the shadow warns the player before the meteor hits the ground
+pub const TELEGRAPH_SECS: f32 = 1.4;
+const FALL_HEIGHT: f32 = 900.0;
+const METEOR_RADIUS: f32 = 24.0;
+const SHADOW_SIZE: Vec2 = Vec2::new(110.0, 18.0);
+const MIN_SHADOW_SCALE: f32 = 0.2;
+
+pub fn spawn_meteor(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    position: Vec2,
+) {
+    // the shadow moves with the ground, the meteor falls towards it as its child
+    commands.spawn((
+        MeteorShadow(Timer::from_seconds(TELEGRAPH_SECS, TimerMode::Once)),
+        Mesh2d(meshes.add(Ellipse::new(SHADOW_SIZE.x / 2.0, SHADOW_SIZE.y / 2.0))),
+        MeshMaterial2d(materials.add(Color::srgba(0.0, 0.0, 0.0, 0.5))),
+        Transform::from_xyz(position.x, position.y, -0.4).with_scale(Vec3::splat(MIN_SHADOW_SCALE)),
+        Visibility::Visible,
+    )).with_children(|shadow| {
+        shadow.spawn((
+            Meteor,
+            Obstacle(ObstacleKind::Meteor),
+            Collider {
+                size: Vec2::splat(METEOR_RADIUS * 1.6),
+            },
+            Transform::from_xyz(0.0, FALL_HEIGHT, 0.5),
+            Visibility::Visible,
+        )).with_children(|meteor| {
+            // fiery tail pointing back up the trajectory
+            meteor.spawn((
+                Mesh2d(meshes.add(Triangle2d::new(
+                    Vec2::new(-METEOR_RADIUS, 0.0),
+                    Vec2::new(METEOR_RADIUS, 0.0),
+                    Vec2::new(0.0, METEOR_RADIUS * 4.0),
+                ))),
+                MeshMaterial2d(materials.add(Color::srgba(1.0, 0.55, 0.1, 0.8))),
+                Transform::from_xyz(0.0, 0.0, -0.1).with_rotation(Quat::from_rotation_z(-PI / 12.0)),
+            ));
+            meteor.spawn((
+                Mesh2d(meshes.add(Circle::new(METEOR_RADIUS))),
+                MeshMaterial2d(materials.add(Color::srgb(0.4, 0.2, 0.15))),
+                Transform::from_xyz(0.0, 0.0, 0.0),
+            ));
+        });
+    });
+}
+
+// above its shadow, as the fall goes from 0 to 1, at a steady pace, speeding up at the end leaves meteors
+// coming down just behind the dino too low to run under and too high to jump over
+pub fn meteor_height(progress: f32) -> f32 {
+    FALL_HEIGHT * (1.0 - progress) + METEOR_RADIUS
+}
+
+#[allow(clippy::type_complexity)]
+pub fn fall_meteors(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut shadows: Query<(Entity, &mut MeteorShadow, &mut Transform)>,
+    mut meteors: Query<(&ChildOf, &mut Transform), (With<Meteor>, Without<MeteorShadow>)>,
+) {
+    for (entity, mut shadow, mut transform) in shadows.iter_mut() {
+        shadow.0.tick(time.delta());
+        let progress = shadow.0.fraction();
+        // the shadow grows as the meteor gets closer
+        let scale = MIN_SHADOW_SCALE + (1.0 - MIN_SHADOW_SCALE) * progress;
+        transform.scale = Vec3::new(scale, scale, 1.0);
+
+        if shadow.0.finished() {
+            // impact, the meteor burns up
+            commands.entity(entity).try_despawn();
+        }
+    }
+
+    for (parent, mut transform) in meteors.iter_mut() {
+        if let Ok((_, shadow, shadow_transform)) = shadows.get(parent.0) {
+            // accelerate towards the ground, counteracting the parent's scale
+            let height = meteor_height(shadow.0.fraction());
+            transform.translation.y = height / shadow_transform.scale.y;
+            transform.scale = Vec3::new(1.0 / shadow_transform.scale.x, 1.0 / shadow_transform.scale.y, 1.0);
+        }
+    }
+}
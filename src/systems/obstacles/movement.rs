@@ -1,26 +1,41 @@
-use crate::components::{AnimationIndices, AnimationTimer, CactusArm, CactusRoot, Collider, HealthPickup, IsHit, Pterodactyl, PterodactylCollider, Velocity};
-use crate::constants::{GAME_SPEED, GROUND_LEVEL, PTERO_SIZE, PTERO_SIZE_X, PTERO_SIZE_Y};
-use crate::resources::{CactusTexture, HealthPickUpImg, ObstacleSpawningTimer, PterodactylFly};
+use crate::assets::{Biome, SpawnKind};
+use crate::components::{AnimationIndices, AnimationTimer, CactusArm, CactusRoot, Collider, IsHit, LavaPit, MeteorShadow, Obstacle, ObstacleKind, PowerUp, Pterodactyl, PterodactylCollider, Velocity};
+use crate::constants::{GAME_SPEED, GROUND_LEVEL, PLAYER_X, PTERO_SIZE, PTERO_SIZE_X, PTERO_SIZE_Y};
+use crate::resources::{Biomes, CactusTexture, HealthPickUpImg, ObstacleSpawningTimer, PterodactylFly, VisibleArea};
+use crate::systems::obstacles::boulder::{spawn_boulder, ROLL_SPEED};
 use crate::systems::obstacles::cactus::spawn_cactus;
+use crate::systems::obstacles::lava::spawn_lava_pit;
+use crate::systems::obstacles::meteor::spawn_meteor;
+use crate::systems::obstacles::pickup::{random_power_up_kind, spawn_power_up};
 use bevy::prelude::*;
 use bevy_prng::WyRand;
 use bevy_rand::global::GlobalEntropy;
 use rand::Rng;
 use rand_core::RngCore;
+use std::ops::Range;
 
-const GROUND_SIZE: Vec2 = Vec2::new(1400.0, 10.0);
-const GROUND_EDGE: f32 = GROUND_SIZE.x / 2.0;
-const SKY_SPAWN_CHANCE: f32 = 0.3;
-const FLY_SPEED: f32 = 100.0;
+// obstacles spawn this far beyond the right edge of the screen, give or take 150 pixels
+const SPAWN_MARGIN: f32 = 260.0;
+// and are despawned once this far past the left edge
+pub const DESPAWN_MARGIN: f32 = 60.0;
+pub const FLY_SPEED: f32 = 100.0;
+// obstacles reach the dino at least this long after the one before, time enough to land from one jump and take the next
+pub const MIN_ARRIVAL_GAP: f32 = 1.2;
 
-const HEALTH_SIZE_X: u32 = 544;
-const HEALTH_SIZE_Y: u32 = 457;
-const HEALTH_SCALE: f32 = 40./HEALTH_SIZE_X as f32;
-const HEALTH_PICKUP_SIZE: Vec2 = Vec2::new(HEALTH_SIZE_X as f32 * HEALTH_SCALE, HEALTH_SIZE_Y as f32 * HEALTH_SCALE);
+// where meteor shadows appear, on the right half of the screen so there's time to see them coming
+pub fn meteor_spawn_range(visible: &VisibleArea) -> Range<f32> {
+    0.0..visible.half_size.x
+}
 
-const SKY_OBSTACLE_CHANCE: f32 = 0.5;
+// pushes an obstacle coming at `speed` back far enough to keep its distance from the last one, and books its arrival
+fn keep_distance(x: f32, speed: f32, now: f32, next_arrival: &mut f32) -> f32 {
+    let x = x.max(PLAYER_X + speed * (*next_arrival - now));
+    *next_arrival = now + (x - PLAYER_X) / speed + MIN_ARRIVAL_GAP;
+    x
+}
 
 
+#[allow(clippy::type_complexity)]
 pub fn drop_obstacles(time: Res<Time>,
                       mut transforms: Query<(&IsHit, &mut Transform, &mut Velocity, &GlobalTransform), Or<(With<Pterodactyl>, With<CactusArm>)>>,
 
@@ -38,40 +53,45 @@ pub fn drop_obstacles(time: Res<Time>,
                 velocity.0.y = 0.0;
                 // undo the incremental transforms on y and z
                 transform.translation.y = orig_transform_y;
-                transform.rotate_z(-1.0 * ang_vel * time.delta_secs());
+                transform.rotate_z(-ang_vel * time.delta_secs());
             }
         }
     }
 }
 
+#[allow(clippy::type_complexity)]
 pub fn move_sky_obstacles(
     time: Res<Time>,
     mut commands: Commands,
-    mut transforms: Query<(Entity, &mut Transform), Or<(With<HealthPickup>, With<Pterodactyl>)>>,
+    visible: Res<VisibleArea>,
+    mut transforms: Query<(Entity, &mut Transform), Or<(With<PowerUp>, With<Pterodactyl>)>>,
 ) {
     // Move obstacles
     for (entity, mut transform) in transforms.iter_mut() {
         transform.translation.x -= (GAME_SPEED + FLY_SPEED) * time.delta_secs();
-        if transform.translation.x < -GROUND_EDGE {
+        if transform.translation.x < -visible.half_size.x - DESPAWN_MARGIN {
             commands.entity(entity).try_despawn();
         }
     }
 }
 
+#[allow(clippy::type_complexity)]
 pub fn move_ground_obstacles(
     time: Res<Time>,
     mut commands: Commands,
-    mut transforms: Query<(Entity, &mut Transform), With<CactusRoot>>,
+    visible: Res<VisibleArea>,
+    mut transforms: Query<(Entity, &mut Transform), Or<(With<CactusRoot>, With<LavaPit>, With<MeteorShadow>)>>,
 ) {
     // Move obstacles
     for (entity, mut transform) in transforms.iter_mut() {
         transform.translation.x -= GAME_SPEED * time.delta_secs();
-        if transform.translation.x < -GROUND_EDGE {
+        if transform.translation.x < -visible.half_size.x - DESPAWN_MARGIN {
             commands.entity(entity).try_despawn();
         }
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn spawn_obstacles(
     mut commands: Commands,
     time: Res<Time>,
@@ -79,21 +99,28 @@ pub fn spawn_obstacles(
     health_pickup: Res<HealthPickUpImg>,
     cactus_texture: Res<CactusTexture>,
     pterodactyl_fly: Res<PterodactylFly>,
+    biomes: Res<Biomes>,
+    biome_assets: Res<Assets<Biome>>,
+    visible: Res<VisibleArea>,
     mut rng: GlobalEntropy<WyRand>,
     meshes: ResMut<Assets<Mesh>>,
     materials: ResMut<Assets<ColorMaterial>>,
-    mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>
+    mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+    mut next_arrival: Local<f32>,
 ) {
     spawn_timer.0.tick(time.delta());
     if spawn_timer.0.finished() {
-        let obstacle_x = GROUND_EDGE + 200.0 + rng.next_u32() as f32 % 300.0 - 150.0;
-        let rand_n = rng.next_u32() % 100;
-        // Randomly decide whether to spawn obstacle or health pickup
-        if rand_n < (SKY_SPAWN_CHANCE * 100.0) as u32 {
-            let obstacle_y = rng.gen_range(GROUND_LEVEL+100.0..-GROUND_LEVEL);
-
-            // pterodactyl
-            if rand_n < (SKY_OBSTACLE_CHANCE * SKY_SPAWN_CHANCE * 100.0) as u32 {
+        let now = time.elapsed_secs();
+        let obstacle_x = visible.half_size.x + SPAWN_MARGIN + rng.next_u32() as f32 % 300.0 - 150.0;
+        // each biome has its own mix of obstacles
+        let palette = biome_assets
+            .get(&biomes.handles[biomes.current])
+            .map(|biome| biome.obstacles.clone())
+            .unwrap_or_default();
+        match palette.pick(rng.next_u32()) {
+            SpawnKind::Pterodactyl => {
+                let obstacle_x = keep_distance(obstacle_x, GAME_SPEED + FLY_SPEED, now, &mut next_arrival);
+                let obstacle_y = rng.gen_range(GROUND_LEVEL+100.0..-GROUND_LEVEL);
                 let layout = TextureAtlasLayout::from_grid(UVec2::new(PTERO_SIZE_X, PTERO_SIZE_Y), 4, 3, None, None);
                 let texture_atlas_layout = texture_atlas_layouts.add(layout);
 
@@ -116,32 +143,42 @@ pub fn spawn_obstacles(
                 )).with_children(|ptero| {
                     ptero.spawn((
                         PterodactylCollider,
+                        Obstacle(ObstacleKind::Pterodactyl),
                         Collider {
                             size: PTERO_SIZE,
                         },
                         Transform::from_xyz(0.0, 0.0, 0.0),
                     ));
                 });
-            } else {
-                // food
-                let obstacle_y = obstacle_y - 40.;
-                commands.spawn((
-                    HealthPickup,
-                    Sprite {
-                        image: health_pickup.0.clone(),
-                        custom_size: Some(HEALTH_PICKUP_SIZE),
-                        ..default()
-                    },
-                    Transform::from_xyz(obstacle_x, obstacle_y, 0.0),
-                    Collider{
-                        size : HEALTH_PICKUP_SIZE,
-                    }
-                ));
             }
-
-        } else {
-            let obstacle_y = GROUND_LEVEL;
-            spawn_cactus(commands, meshes, materials,cactus_texture, Vec2::new(obstacle_x, obstacle_y), &mut rng);
+            SpawnKind::Pickup => {
+                // food and other power-ups
+                let obstacle_y = rng.gen_range(GROUND_LEVEL+100.0..-GROUND_LEVEL) - 40.;
+                let kind = random_power_up_kind(&mut rng);
+                spawn_power_up(commands, meshes, materials, health_pickup, Vec2::new(obstacle_x, obstacle_y), kind);
+            }
+            SpawnKind::Meteor => {
+                // the shadow appears on screen so the landing spot is telegraphed, it's left out when there's no room for it
+                let range = meteor_spawn_range(&visible);
+                let start = range.start.max(PLAYER_X + GAME_SPEED * (*next_arrival - now));
+                if start >= range.end {
+                    return;
+                }
+                let obstacle_x = keep_distance(rng.gen_range(start..range.end), GAME_SPEED, now, &mut next_arrival);
+                spawn_meteor(commands, meshes, materials, Vec2::new(obstacle_x, GROUND_LEVEL));
+            }
+            SpawnKind::Boulder => {
+                let obstacle_x = keep_distance(obstacle_x, GAME_SPEED + ROLL_SPEED, now, &mut next_arrival);
+                spawn_boulder(commands, meshes, materials, Vec2::new(obstacle_x, GROUND_LEVEL), &mut rng);
+            }
+            SpawnKind::LavaPit => {
+                let obstacle_x = keep_distance(obstacle_x, GAME_SPEED, now, &mut next_arrival);
+                spawn_lava_pit(commands, meshes, materials, Vec2::new(obstacle_x, GROUND_LEVEL), &mut rng);
+            }
+            SpawnKind::Cactus => {
+                let obstacle_x = keep_distance(obstacle_x, GAME_SPEED, now, &mut next_arrival);
+                spawn_cactus(commands, meshes, materials,cactus_texture, Vec2::new(obstacle_x, GROUND_LEVEL), &mut rng);
+            }
         }
     }
 }
@@ -0,0 +1,73 @@
+use crate::components::{Collider, Graze, Obstacle, ObstacleKind, PlayerCollider};
+use crate::events::NearMiss;
+use crate::resources::NearMissFlash;
+use crate::systems::game::popup::spawn_popup;
+use bevy::prelude::*;
+
+// how far the graze zone extends beyond each obstacle collider
+pub const GRAZE_MARGIN: f32 = 40.0;
+pub const NEAR_MISS_SLOW_MO_FACTOR: f32 = 0.5;
+const POPUP_COLOR: Color = Color::srgb(1.0, 0.6, 0.0);
+
+// gap between two boxes, negative when they overlap
+pub fn box_distance(pos1: Vec3, half_size1: Vec2, pos2: Vec3, half_size2: Vec2) -> f32 {
+    let gap_x = (pos1.x - pos2.x).abs() - (half_size1.x + half_size2.x);
+    let gap_y = (pos1.y - pos2.y).abs() - (half_size1.y + half_size2.y);
+    gap_x.max(gap_y)
+}
+
+pub fn detect_near_misses(
+    mut commands: Commands,
+    player_collider_query: Query<(&GlobalTransform, &Collider), With<PlayerCollider>>,
+    mut obstacle_query: Query<(Entity, &GlobalTransform, &Collider, &Obstacle, Option<&mut Graze>)>,
+    mut near_misses: EventWriter<NearMiss>,
+) {
+    let Ok((player_transform, player_collider)) = player_collider_query.single() else {
+        return;
+    };
+    let player_half = player_collider.size / 2.0;
+
+    for (entity, transform, collider, obstacle, graze) in obstacle_query.iter_mut() {
+        let distance = box_distance(player_transform.translation(), player_half, transform.translation(), collider.size / 2.0);
+        match graze {
+            Some(mut graze) if distance <= GRAZE_MARGIN => {
+                graze.closest = graze.closest.min(distance);
+                graze.touched |= distance <= 0.0;
+            }
+            // left the graze zone, it only counts if the player never touched it
+            Some(graze) => {
+                if !graze.touched {
+                    near_misses.write(NearMiss { kind: obstacle.0, distance: graze.closest });
+                }
+                commands.entity(entity).try_remove::<Graze>();
+            }
+            None if distance <= GRAZE_MARGIN => {
+                // may have been hit or landed and despawned this frame
+                commands.entity(entity).try_insert(Graze { closest: distance, touched: distance <= 0.0 });
+            }
+            None => {}
+        }
+    }
+}
+
+pub fn near_miss_feedback(
+    mut commands: Commands,
+    mut near_misses: EventReader<NearMiss>,
+    mut flash: ResMut<NearMissFlash>,
+) {
+    if let Some(near_miss) = near_misses.read().last() {
+        flash.0.reset();
+        let text = match near_miss.kind {
+            ObstacleKind::LavaPit => "Too hot!",
+            ObstacleKind::Meteor => "Dodged!",
+            _ => "Close call!",
+        };
+        spawn_popup(&mut commands, text, POPUP_COLOR);
+    }
+}
+
+pub fn tick_near_miss_flash(real_time: Res<Time<Real>>, time: Res<Time<Virtual>>, mut flash: ResMut<NearMissFlash>) {
+    if !time.is_paused() {
+        flash.0.tick(real_time.delta());
+    }
+}
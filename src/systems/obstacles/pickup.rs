@@ -0,0 +1,85 @@
+use crate::components::{Collider, PowerUp, PowerUpKind};
+use crate::resources::HealthPickUpImg;
+use bevy::prelude::*;
+use bevy_prng::WyRand;
+use bevy_rand::global::GlobalEntropy;
+use rand_core::RngCore;
+
+const HEALTH_SIZE_X: u32 = 544;
+const HEALTH_SIZE_Y: u32 = 457;
+const HEALTH_SCALE: f32 = 40./HEALTH_SIZE_X as f32;
+const HEALTH_PICKUP_SIZE: Vec2 = Vec2::new(HEALTH_SIZE_X as f32 * HEALTH_SCALE, HEALTH_SIZE_Y as f32 * HEALTH_SCALE);
+const POWER_UP_RADIUS: f32 = 18.0;
+
+// the donut is as likely as all other power-ups together
+const HEALTH_CHANCE: f32 = 0.5;
+const POWER_UP_KINDS: [PowerUpKind; 5] = [
+    PowerUpKind::Shield,
+    PowerUpKind::Magnet,
+    PowerUpKind::SlowMo,
+    PowerUpKind::DoubleJump,
+    PowerUpKind::ScoreMultiplier,
+];
+
+pub fn random_power_up_kind(rng: &mut GlobalEntropy<WyRand>) -> PowerUpKind {
+    if rng.next_u32() % 100 < (HEALTH_CHANCE * 100.0) as u32 {
+        PowerUpKind::Health
+    } else {
+        POWER_UP_KINDS[rng.next_u32() as usize % POWER_UP_KINDS.len()]
+    }
+}
+
+pub fn spawn_power_up(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    health_pickup: Res<HealthPickUpImg>,
+    position: Vec2,
+    kind: PowerUpKind,
+) {
+    let transform = Transform::from_xyz(position.x, position.y, 0.0);
+    if kind == PowerUpKind::Health {
+        // food
+        commands.spawn((
+            PowerUp(kind),
+            Sprite {
+                image: health_pickup.0.clone(),
+                custom_size: Some(HEALTH_PICKUP_SIZE),
+                ..default()
+            },
+            transform,
+            Collider{
+                size : HEALTH_PICKUP_SIZE,
+            }
+        ));
+        return;
+    }
+
+    let (icon, color): (Mesh, Color) = match kind {
+        PowerUpKind::Shield => (RegularPolygon::new(POWER_UP_RADIUS * 0.6, 6).into(), Color::srgb(0.3, 0.7, 1.0)),
+        PowerUpKind::Magnet => (Annulus::new(POWER_UP_RADIUS * 0.3, POWER_UP_RADIUS * 0.6).into(), Color::srgb(0.9, 0.2, 0.2)),
+        PowerUpKind::SlowMo => (Rectangle::new(POWER_UP_RADIUS * 0.25, POWER_UP_RADIUS).into(), Color::srgb(0.6, 0.3, 0.9)),
+        PowerUpKind::DoubleJump => (Triangle2d::new(
+            Vec2::new(-POWER_UP_RADIUS * 0.5, -POWER_UP_RADIUS * 0.4),
+            Vec2::new(POWER_UP_RADIUS * 0.5, -POWER_UP_RADIUS * 0.4),
+            Vec2::new(0.0, POWER_UP_RADIUS * 0.6),
+        ).into(), Color::srgb(0.2, 0.8, 0.3)),
+        _ => (RegularPolygon::new(POWER_UP_RADIUS * 0.6, 5).into(), Color::srgb(1.0, 0.8, 0.1)),
+    };
+
+    commands.spawn((
+        PowerUp(kind),
+        Mesh2d(meshes.add(Circle::new(POWER_UP_RADIUS))),
+        MeshMaterial2d(materials.add(color)),
+        transform,
+        Collider {
+            size: Vec2::splat(POWER_UP_RADIUS * 2.0),
+        },
+    )).with_children(|power_up| {
+        power_up.spawn((
+            Mesh2d(meshes.add(icon)),
+            MeshMaterial2d(materials.add(Color::WHITE)),
+            Transform::from_xyz(0.0, 0.0, 0.1),
+        ));
+    });
+}
@@ -0,0 +1,127 @@
+use crate::assets::{tint_color, EmitterConfig};
+use crate::components::{ObstacleKind, Particle, ParticleKind, PowerUpKind};
+use crate::constants::GAME_SPEED;
+use crate::events::{ObstacleHit, ParticleBurst, PickupCollected};
+use crate::resources::ParticleEmitters;
+use bevy::prelude::*;
+use bevy_prng::WyRand;
+use bevy_rand::global::GlobalEntropy;
+use rand::Rng;
+
+// keeps a pile of bursts from bogging down slow devices
+const MAX_PARTICLES: usize = 400;
+// in front of obstacles and the player
+const PARTICLE_Z: f32 = 2.0;
+
+impl ParticleEmitters {
+    pub fn get(&self, kind: ParticleKind) -> &Handle<EmitterConfig> {
+        match kind {
+            ParticleKind::Dust => &self.dust,
+            ParticleKind::Sparks => &self.sparks,
+            ParticleKind::Feathers => &self.feathers,
+            ParticleKind::Crumbs => &self.crumbs,
+        }
+    }
+}
+
+pub fn load_particle_emitters(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(ParticleEmitters {
+        dust: asset_server.load("particles/dust.emitter.ron"),
+        sparks: asset_server.load("particles/sparks.emitter.ron"),
+        feathers: asset_server.load("particles/feathers.emitter.ron"),
+        crumbs: asset_server.load("particles/crumbs.emitter.ron"),
+    });
+}
+
+pub fn burst_on_hits(
+    mut obstacle_hits: EventReader<ObstacleHit>,
+    mut pickups: EventReader<PickupCollected>,
+    transforms: Query<&GlobalTransform>,
+    mut bursts: EventWriter<ParticleBurst>,
+) {
+    for hit in obstacle_hits.read() {
+        let kind = match hit.kind {
+            ObstacleKind::Cactus => ParticleKind::Sparks,
+            ObstacleKind::Pterodactyl => ParticleKind::Feathers,
+            _ => continue,
+        };
+        if let Ok(transform) = transforms.get(hit.entity) {
+            bursts.write(ParticleBurst { kind, position: transform.translation().truncate() });
+        }
+    }
+    for pickup in pickups.read().filter(|pickup| pickup.kind == PowerUpKind::Health) {
+        if let Ok(transform) = transforms.get(pickup.entity) {
+            bursts.write(ParticleBurst { kind: ParticleKind::Crumbs, position: transform.translation().truncate() });
+        }
+    }
+}
+
+pub fn emit_particles(
+    mut commands: Commands,
+    mut bursts: EventReader<ParticleBurst>,
+    emitters: Res<ParticleEmitters>,
+    configs: Res<Assets<EmitterConfig>>,
+    particles: Query<(), With<Particle>>,
+    mut rng: GlobalEntropy<WyRand>,
+) {
+    let mut live = particles.iter().count();
+    for burst in bursts.read() {
+        // skipped until the emitter has loaded
+        let Some(config) = configs.get(emitters.get(burst.kind)) else {
+            continue;
+        };
+        let count = rng.gen_range(config.count.0..=config.count.1).min(MAX_PARTICLES.saturating_sub(live) as u32);
+        live += count as usize;
+
+        for _ in 0..count {
+            let angle = rng.gen_range(config.angle.0..=config.angle.1).to_radians();
+            let speed = rng.gen_range(config.speed.0..=config.speed.1);
+            let size = rng.gen_range(config.size.0..=config.size.1);
+            let size = Vec2::new(size * config.aspect, size);
+            let color = config.colors[rng.gen_range(0..config.colors.len())];
+            commands.spawn((
+                Particle {
+                    velocity: Vec2::from_angle(angle) * speed,
+                    spin: rng.gen_range(config.spin.0..=config.spin.1),
+                    gravity: config.gravity,
+                    drag: config.drag,
+                    scroll: config.scroll,
+                    shrink: config.shrink,
+                    size,
+                    lifetime: Timer::from_seconds(rng.gen_range(config.lifetime.0..=config.lifetime.1), TimerMode::Once),
+                },
+                Sprite::from_color(tint_color(color), size),
+                Transform::from_translation(burst.position.extend(PARTICLE_Z))
+                    .with_rotation(Quat::from_rotation_z(angle)),
+            ));
+        }
+    }
+}
+
+pub fn update_particles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut particles: Query<(Entity, &mut Particle, &mut Sprite, &mut Transform)>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut particle, mut sprite, mut transform) in particles.iter_mut() {
+        particle.lifetime.tick(time.delta());
+        if particle.lifetime.finished() {
+            commands.entity(entity).try_despawn();
+            continue;
+        }
+        particle.velocity.y += particle.gravity * delta;
+        let damping = (1.0 - particle.drag * delta).max(0.0);
+        particle.velocity *= damping;
+
+        transform.translation.x += (particle.velocity.x - particle.scroll * GAME_SPEED) * delta;
+        transform.translation.y += particle.velocity.y * delta;
+        transform.rotate_z(particle.spin * delta);
+
+        let remaining = 1.0 - particle.lifetime.fraction();
+        sprite.color.set_alpha(remaining);
+        if particle.shrink {
+            sprite.custom_size = Some(particle.size * remaining);
+        }
+    }
+}
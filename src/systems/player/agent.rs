@@ -0,0 +1,83 @@
+use crate::components::{Collider, Health, Obstacle, Player, PlayerCollider, Velocity};
+use crate::constants::{DINO_DIE_SIZE, DINO_DUCK_SIZE, GROUND_LEVEL};
+use crate::events::PlayerAction;
+use crate::resources::Brain;
+use crate::systems::player::autopilot::approach_speed;
+use bevy::prelude::*;
+use my_bevy_game::agent::{AgentAction, Observation, ObstacleObservation, PlayerObservation, PlayerState, FRAMES_PER_STEP, OBSERVED_OBSTACLES};
+
+// presses and releases jump and duck as the agent's action changes, so holding jump jumps once
+pub fn agent_actions(action: Res<AgentAction>, mut held: Local<AgentAction>, mut actions: EventWriter<PlayerAction>) {
+    if *action == *held {
+        return;
+    }
+    if *held == AgentAction::Duck {
+        actions.write(PlayerAction::StopDuck);
+    }
+    match *action {
+        AgentAction::Jump => {
+            actions.write(PlayerAction::Jump);
+        }
+        AgentAction::Duck => {
+            actions.write(PlayerAction::StartDuck);
+        }
+        AgentAction::Noop => {}
+    }
+    *held = *action;
+}
+
+// the network decides as often as it got to in training, from what was observed after the last frame
+pub fn drive_network(time: Res<Time<Virtual>>, brain: Res<Brain>, observation: Res<Observation>, mut frames: Local<u32>, mut action: ResMut<AgentAction>) {
+    if time.is_paused() {
+        return;
+    }
+    if frames.is_multiple_of(FRAMES_PER_STEP) {
+        *action = brain.0.decide(&observation);
+    }
+    *frames = frames.wrapping_add(1);
+}
+
+pub fn observe(
+    time: Res<Time<Virtual>>,
+    player_query: Query<(&Transform, &Sprite, &Velocity), With<Player>>,
+    player_collider: Query<(&GlobalTransform, &Collider, &Health), With<PlayerCollider>>,
+    obstacles: Query<(&GlobalTransform, &Collider, &Obstacle)>,
+    mut observation: ResMut<Observation>,
+) {
+    let (Ok((transform, sprite, velocity)), Ok((collider_transform, collider, health))) = (player_query.single(), player_collider.single()) else {
+        return;
+    };
+    let feet = transform.translation.truncate();
+    let state = if health.0 == 0 || sprite.custom_size == Some(DINO_DIE_SIZE) {
+        PlayerState::Dead
+    } else if feet.y > GROUND_LEVEL || velocity.0.y > 0.0 {
+        PlayerState::Jumping
+    } else if sprite.custom_size == Some(DINO_DUCK_SIZE) {
+        PlayerState::Ducking
+    } else {
+        PlayerState::Running
+    };
+    observation.player = PlayerObservation {
+        y: feet.y - GROUND_LEVEL,
+        velocity: velocity.0.y,
+        state,
+    };
+
+    // anything that has passed the dino's back can't hurt it anymore
+    let back = collider_transform.translation().x - collider.size.x / 2.0;
+    let mut ahead: Vec<ObstacleObservation> = obstacles
+        .iter()
+        .filter(|(transform, collider, _)| transform.translation().x + collider.size.x / 2.0 >= back)
+        .map(|(transform, collider, obstacle)| ObstacleObservation {
+            kind: obstacle.0,
+            x: transform.translation().x - feet.x,
+            y: transform.translation().y - GROUND_LEVEL,
+            width: collider.size.x,
+            height: collider.size.y,
+            speed: approach_speed(obstacle.0) * time.relative_speed(),
+        })
+        .collect();
+    ahead.sort_by(|a, b| a.x.total_cmp(&b.x));
+    ahead.truncate(OBSERVED_OBSTACLES);
+    observation.obstacles = ahead;
+}
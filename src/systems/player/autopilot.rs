@@ -0,0 +1,439 @@
+use crate::components::{Collider, MeteorShadow, Obstacle, ObstacleKind, Player, PlayerCollider, Velocity};
+use crate::constants::{DINO_DIE_SIZE, DINO_DUCK_SIZE, DINO_JUMP_SIZE, DINO_RUN_SIZE, GAME_SPEED, GROUND_LEVEL, HIT_BOX_SCALE_X, HIT_BOX_SCALE_Y};
+use crate::events::PlayerAction;
+use crate::systems::obstacles::boulder::ROLL_SPEED;
+use crate::systems::obstacles::collision::is_colliding;
+use crate::systems::obstacles::meteor::meteor_height;
+use crate::systems::obstacles::movement::FLY_SPEED;
+use crate::systems::player::movement::{GRAVITY, JUMP_FORCE};
+use bevy::prelude::*;
+
+// how far ahead the autopilot looks, in seconds of game time, enough for two jumps and a meteor's whole fall
+const LOOKAHEAD_SECS: f32 = 2.0;
+const STEP_SECS: f32 = 1.0 / 120.0;
+// the second of two jumps only has to be safe until shortly after landing, what comes next is up to the next decision
+const LANDING_SECS: f32 = 0.15;
+// room for the difference between the predicted and the frame by frame motion, in pixels
+const CLEARANCE: f32 = 4.0;
+
+// an obstacle collider as the autopilot sees it
+#[derive(Clone, Copy, Debug)]
+pub struct Hazard {
+    pub center: Vec2,
+    pub size: Vec2,
+    // towards the dino, in pixels per second of game time
+    pub speed: f32,
+    // how far a meteor has fallen and how long the whole fall takes, in seconds
+    pub fall: Option<(f32, f32)>,
+}
+
+impl Hazard {
+    fn center_at(&self, secs: f32) -> Option<Vec2> {
+        let mut center = self.center - Vec2::X * self.speed * secs;
+        if let Some((elapsed, duration)) = self.fall {
+            let progress = (elapsed + secs) / duration;
+            // meteors burn up when they land, until then they're checked on the way down
+            if progress > 1.0 {
+                return None;
+            }
+            center.y = GROUND_LEVEL + meteor_height(progress.max(0.0));
+        }
+        Some(center)
+    }
+
+    fn bounds_at(&self, secs: f32) -> Option<Rect> {
+        self.center_at(secs).map(|center| Rect::from_center_size(center, self.size + 2.0 * CLEARANCE))
+    }
+}
+
+// the dino on the ground, with its collider relative to its feet
+#[derive(Clone, Copy, Debug)]
+pub struct Dino {
+    pub feet: Vec2,
+    pub offset: Vec2,
+    pub size: Vec2,
+    pub ducking: bool,
+}
+
+#[derive(Clone, Copy)]
+enum Plan {
+    Run,
+    Duck,
+    // take off this many seconds from now, negative for a jump that could have been
+    Jump(f32),
+    // a jump, then another one this many seconds from now once it's back down
+    JumpAgain(f32, f32),
+}
+
+// the collider of each pose, as set up by the animation functions
+fn run_collider() -> (Vec2, Vec2) {
+    let size = Vec2::new(DINO_RUN_SIZE.x * HIT_BOX_SCALE_X, DINO_RUN_SIZE.y);
+    (Vec2::new(DINO_RUN_SIZE.x - size.x, size.y) / 2.0, size)
+}
+
+fn duck_collider() -> (Vec2, Vec2) {
+    let size = Vec2::new(DINO_DUCK_SIZE.x * HIT_BOX_SCALE_X, DINO_DUCK_SIZE.y * HIT_BOX_SCALE_Y);
+    (Vec2::new(DINO_DUCK_SIZE.x - size.x, size.y) / 2.0, size)
+}
+
+fn jump_size() -> Vec2 {
+    Vec2::new(DINO_JUMP_SIZE.x * HIT_BOX_SCALE_X, DINO_JUMP_SIZE.y)
+}
+
+// the game moves the dino before gravity catches up each frame, so longer frames make for lower, shorter jumps
+fn jump_height(air: f32, frame_secs: f32) -> f32 {
+    JUMP_FORCE * air + GRAVITY / 2.0 * air * (air + frame_secs)
+}
+
+fn air_secs(frame_secs: f32) -> f32 {
+    -2.0 * JUMP_FORCE / GRAVITY - frame_secs
+}
+
+impl Dino {
+    fn bounds_at(&self, plan: Plan, secs: f32, frame_secs: f32) -> Rect {
+        let (offset, size, height) = match plan {
+            Plan::Run if self.ducking => (run_collider().0, run_collider().1, 0.0),
+            Plan::Duck if !self.ducking => (duck_collider().0, duck_collider().1, 0.0),
+            Plan::Run | Plan::Duck => (self.offset, self.size, 0.0),
+            Plan::Jump(delay) if secs < delay => (self.offset, self.size, 0.0),
+            // jumping only resizes the collider, it stays where it was
+            Plan::Jump(delay) if secs < delay + air_secs(frame_secs) => (self.offset, jump_size(), jump_height(secs - delay, frame_secs)),
+            // and landing sizes it for running, even when the dino took off ducking
+            Plan::Jump(_) => (self.offset, run_collider().1, 0.0),
+            Plan::JumpAgain(first, second) if secs < second => return self.bounds_at(Plan::Jump(first), secs, frame_secs),
+            Plan::JumpAgain(_, second) => return self.bounds_at(Plan::Jump(second), secs, frame_secs),
+        };
+        Rect::from_center_size(self.feet + offset + Vec2::Y * height, size)
+    }
+}
+
+// the hazards at every step of the lookahead, worked out once a frame for all the plans tried against them
+struct Lookahead<'a> {
+    dino: &'a Dino,
+    frame_secs: f32,
+    steps: Vec<Vec<Rect>>,
+}
+
+impl<'a> Lookahead<'a> {
+    fn new(dino: &'a Dino, hazards: &[Hazard], frame_secs: f32) -> Self {
+        let steps = (0..=(LOOKAHEAD_SECS / STEP_SECS) as usize)
+            .map(|step| hazards.iter().filter_map(|hazard| hazard.bounds_at(step as f32 * STEP_SECS)).collect())
+            .collect();
+        Lookahead { dino, frame_secs, steps }
+    }
+
+    // seconds until the dino runs into something when following the plan
+    fn first_hit(&self, plan: Plan, until: f32) -> Option<f32> {
+        self.steps
+            .iter()
+            .enumerate()
+            .map(|(step, hazards)| (step as f32 * STEP_SECS, hazards))
+            .take_while(|(secs, _)| *secs <= until)
+            .find(|(secs, hazards)| {
+                let dino = self.dino.bounds_at(plan, *secs, self.frame_secs);
+                hazards.iter().any(|hazard| touching(dino, *hazard))
+            })
+            .map(|(secs, _)| secs)
+    }
+}
+
+fn touching(a: Rect, b: Rect) -> bool {
+    is_colliding(a.center().extend(0.0), a.half_size(), b.center().extend(0.0), b.half_size())
+}
+
+// what to do this frame, if anything, for a dino on the ground
+pub fn decide(dino: &Dino, hazards: &[Hazard], frame_secs: f32) -> Option<PlayerAction> {
+    let never = f32::INFINITY;
+    let ahead = Lookahead::new(dino, hazards, frame_secs);
+    let Some(run) = ahead.first_hit(Plan::Run, LOOKAHEAD_SECS) else {
+        return dino.ducking.then_some(PlayerAction::StopDuck);
+    };
+    let duck = ahead.first_hit(Plan::Duck, LOOKAHEAD_SECS);
+    if duck.is_none() {
+        return (!dino.ducking).then_some(PlayerAction::StartDuck);
+    }
+
+    let air = air_secs(frame_secs);
+    // the dino only leaves the ground on the frame after the jump
+    let takeoff = frame_secs;
+    let step = frame_secs.max(STEP_SECS);
+    let delay = |frames: i32| takeoff + frames as f32 * step;
+    // none if a jump then isn't in the air when the dino would otherwise be hit, or is hit before it lands,
+    // otherwise whether it leaves a way out of whatever comes after it, by running on or jumping again
+    let jump = |delay: f32| {
+        let landing = delay + air;
+        if delay >= run || run >= landing {
+            return None;
+        }
+        let Some(hit) = ahead.first_hit(Plan::Jump(delay), LOOKAHEAD_SECS) else {
+            return Some(true);
+        };
+        if hit <= landing {
+            return None;
+        }
+        let way_out = (0..)
+            .map(|i| landing + takeoff + i as f32 * step)
+            .take_while(|second| *second < hit)
+            .any(|second| {
+                let until = (second + air + LANDING_SECS).min(LOOKAHEAD_SECS);
+                ahead.first_hit(Plan::JumpAgain(delay, second), until).is_none()
+            });
+        Some(way_out)
+    };
+    let safe = |frames: i32| jump(delay(frames)) == Some(true);
+
+    if let Some(way_out) = jump(delay(0)) {
+        if way_out {
+            // take off in the middle of the window of safe take-off times, for the most room either side
+            let later = (1..).take_while(|frames| safe(*frames)).count();
+            let earlier = (1..).take_while(|frames| safe(-frames)).count();
+            return (later <= earlier).then_some(PlayerAction::Jump);
+        }
+        // holding on might still find a way out, a meteor that has to come down first say
+        if (1..).take_while(|frames| delay(*frames) < run).any(safe) {
+            return None;
+        }
+        // last chance, a jump into trouble beats certain trouble
+        return jump(delay(1)).is_none().then_some(PlayerAction::Jump);
+    }
+
+    let duck = duck.unwrap_or(never);
+    if duck > run {
+        // ducking buys time, under a pterodactyl say
+        return (!dino.ducking).then_some(PlayerAction::StartDuck);
+    }
+    if dino.ducking && run >= duck {
+        return Some(PlayerAction::StopDuck);
+    }
+    // about to be hit whatever happens, a jump might still put it off
+    let jump = ahead.first_hit(Plan::Jump(takeoff), LOOKAHEAD_SECS).unwrap_or(never);
+    (run <= frame_secs && jump > run).then_some(PlayerAction::Jump)
+}
+
+pub fn approach_speed(kind: ObstacleKind) -> f32 {
+    match kind {
+        ObstacleKind::Pterodactyl => GAME_SPEED + FLY_SPEED,
+        ObstacleKind::Boulder => GAME_SPEED + ROLL_SPEED,
+        ObstacleKind::Cactus | ObstacleKind::LavaPit | ObstacleKind::Meteor => GAME_SPEED,
+    }
+}
+
+// reads obstacle positions and collider sizes every frame and presses the buttons for the player
+pub fn drive_autopilot(
+    time: Res<Time<Virtual>>,
+    player_query: Query<(&Transform, &Sprite, &Velocity), With<Player>>,
+    player_collider: Query<(&Transform, &Collider), With<PlayerCollider>>,
+    obstacles: Query<(&GlobalTransform, &Collider, &Obstacle, Option<&ChildOf>)>,
+    shadows: Query<&MeteorShadow>,
+    mut actions: EventWriter<PlayerAction>,
+) {
+    let (Ok((transform, sprite, velocity)), Ok((collider_transform, collider))) = (player_query.single(), player_collider.single()) else {
+        return;
+    };
+    // nothing to do in the air, including the frame the dino takes off
+    let airborne = transform.translation.y > GROUND_LEVEL || velocity.0.y > 0.0;
+    if time.is_paused() || airborne || sprite.custom_size == Some(DINO_DIE_SIZE) {
+        return;
+    }
+    let dino = Dino {
+        feet: transform.translation.truncate(),
+        offset: collider_transform.translation.truncate(),
+        size: collider.size,
+        ducking: sprite.custom_size == Some(DINO_DUCK_SIZE),
+    };
+    let hazards: Vec<Hazard> = obstacles
+        .iter()
+        .map(|(transform, collider, obstacle, parent)| Hazard {
+            center: transform.translation().truncate(),
+            size: collider.size,
+            speed: approach_speed(obstacle.0),
+            // colliders are where they were left last frame, the fall has been timed since, so it's taken back a frame
+            fall: parent
+                .and_then(|parent| shadows.get(parent.parent()).ok())
+                .map(|shadow| ((shadow.0.elapsed_secs() - time.delta_secs()).max(0.0), shadow.0.duration().as_secs_f32())),
+        })
+        .collect();
+
+    let action = decide(&dino, &hazards, time.delta_secs());
+    if let Some(action) = action {
+        actions.write(action);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{PLAYER_X, PTERO_SIZE};
+    use crate::resources::VisibleArea;
+    use crate::systems::obstacles::meteor::TELEGRAPH_SECS;
+    use crate::systems::obstacles::movement::{meteor_spawn_range, MIN_ARRIVAL_GAP};
+
+    const FRAME_SECS: f32 = 1.0 / 60.0;
+    const METEOR_SIZE: Vec2 = Vec2::splat(38.4);
+
+    fn running() -> Dino {
+        let (offset, size) = run_collider();
+        Dino { feet: Vec2::new(PLAYER_X, GROUND_LEVEL), offset, size, ducking: false }
+    }
+
+    fn ground(x: f32, size: Vec2, kind: ObstacleKind) -> Hazard {
+        Hazard { center: Vec2::new(x, GROUND_LEVEL + size.y / 2.0), size, speed: approach_speed(kind), fall: None }
+    }
+
+    fn pterodactyl(x: f32, height: f32) -> Hazard {
+        Hazard { center: Vec2::new(x, GROUND_LEVEL + height), size: PTERO_SIZE, speed: approach_speed(ObstacleKind::Pterodactyl), fall: None }
+    }
+
+    // a meteor whose shadow has just appeared at x
+    fn meteor(x: f32) -> Hazard {
+        Hazard { fall: Some((0.0, TELEGRAPH_SECS)), ..ground(x, METEOR_SIZE, ObstacleKind::Meteor) }
+    }
+
+    // plays the dino against the hazards the way the game moves them, a frame at a time, and reports whether it was hit
+    fn survives(hazards: &[Hazard], secs: f32, frame_secs: f32) -> bool {
+        let mut dino = running();
+        let mut hazards = hazards.to_vec();
+        let (mut height, mut velocity, mut jumping) = (0.0, 0.0, false);
+        for _ in 0..(secs / frame_secs) as usize {
+            // collisions are checked where everything was left the frame before
+            let size = if jumping { jump_size() } else { dino.size };
+            let bounds = Rect::from_center_size(dino.feet + dino.offset + Vec2::Y * height, size);
+            let hit = hazards
+                .iter()
+                .filter_map(|hazard| hazard.center_at(0.0).map(|center| Rect::from_center_size(center, hazard.size)))
+                .any(|hazard| touching(bounds, hazard));
+            if hit {
+                return false;
+            }
+            let action = (height <= 0.0 && velocity <= 0.0).then(|| decide(&dino, &hazards, frame_secs)).flatten();
+            // the dino moves, then takes the action, then gravity pulls on it
+            height += velocity * frame_secs;
+            if height <= 0.0 {
+                height = 0.0;
+                velocity = 0.0;
+                if jumping {
+                    jumping = false;
+                    dino.size = run_collider().1;
+                }
+            }
+            match action {
+                Some(PlayerAction::Jump) => {
+                    velocity = JUMP_FORCE;
+                    jumping = true;
+                    dino.ducking = false;
+                }
+                Some(PlayerAction::StartDuck) => {
+                    (dino.offset, dino.size) = duck_collider();
+                    dino.ducking = true;
+                }
+                Some(PlayerAction::StopDuck) => {
+                    (dino.offset, dino.size) = run_collider();
+                    dino.ducking = false;
+                }
+                None => {}
+            }
+            velocity += GRAVITY * frame_secs;
+            for hazard in &mut hazards {
+                hazard.center.x -= hazard.speed * frame_secs;
+                if let Some((elapsed, _)) = &mut hazard.fall {
+                    *elapsed += frame_secs;
+                }
+            }
+        }
+        true
+    }
+
+    #[test]
+    fn jumps_over_a_cactus() {
+        let cactus = [ground(400.0, Vec2::new(80.0, 150.0), ObstacleKind::Cactus)];
+        // too far off to jump yet
+        assert_eq!(decide(&running(), &cactus, FRAME_SECS), None);
+        assert!(survives(&cactus, 2.0, FRAME_SECS));
+        // and when the game has sped up to three times the frame time
+        assert!(survives(&cactus, 2.0, 3.0 * FRAME_SECS));
+    }
+
+    #[test]
+    fn ducks_under_a_low_pterodactyl() {
+        let hazards = [pterodactyl(200.0, 160.0)];
+        assert_eq!(decide(&running(), &hazards, FRAME_SECS), Some(PlayerAction::StartDuck));
+        assert!(survives(&hazards, 2.0, FRAME_SECS));
+    }
+
+    #[test]
+    fn ignores_what_passes_overhead() {
+        assert_eq!(decide(&running(), &[pterodactyl(200.0, 400.0)], FRAME_SECS), None);
+    }
+
+    #[test]
+    fn clears_lava_boulders_and_meteors() {
+        let lava = ground(500.0, Vec2::new(75.0, 20.0), ObstacleKind::LavaPit);
+        let boulder = ground(900.0, Vec2::splat(80.0), ObstacleKind::Boulder);
+        assert!(survives(&[lava], 2.0, FRAME_SECS));
+        assert!(survives(&[boulder], 2.0, FRAME_SECS));
+        // lands on the dino's head unless it jumps clear
+        let meteor = Hazard { fall: Some((0.4, 1.4)), ..ground(230.0, METEOR_SIZE, ObstacleKind::Meteor) };
+        let dino = running();
+        let ahead = Lookahead::new(&dino, &[meteor], FRAME_SECS);
+        assert!(ahead.first_hit(Plan::Run, LOOKAHEAD_SECS).is_some());
+        assert!(survives(&[meteor], 2.0, FRAME_SECS));
+    }
+
+    #[test]
+    fn jumps_a_boulder_as_close_behind_a_cactus_as_they_spawn() {
+        let cactus = ground(PLAYER_X + approach_speed(ObstacleKind::Cactus), Vec2::new(52.0, 85.0), ObstacleKind::Cactus);
+        let x = PLAYER_X + approach_speed(ObstacleKind::Boulder) * (1.0 + MIN_ARRIVAL_GAP);
+        let hazards = [cactus, ground(x, Vec2::splat(80.0), ObstacleKind::Boulder)];
+        assert!(survives(&hazards, 3.0, FRAME_SECS));
+        assert!(survives(&hazards, 3.0, 3.0 * FRAME_SECS));
+    }
+
+    #[test]
+    fn dodges_a_meteor_landing_where_it_stands() {
+        let (offset, _) = run_collider();
+        // the shadow appears where the ground will have carried it under the dino by the time the meteor lands
+        let x = PLAYER_X + offset.x + approach_speed(ObstacleKind::Meteor) * TELEGRAPH_SECS;
+        assert!(meteor_spawn_range(&VisibleArea::default()).contains(&x));
+        let dino = running();
+        let ahead = Lookahead::new(&dino, &[meteor(x)], FRAME_SECS);
+        assert!(ahead.first_hit(Plan::Run, LOOKAHEAD_SECS).is_some());
+        assert!(ahead.first_hit(Plan::Duck, LOOKAHEAD_SECS).is_some());
+        assert!(survives(&[meteor(x)], 2.0, FRAME_SECS));
+        assert!(survives(&[meteor(x)], 2.0, 2.0 * FRAME_SECS));
+    }
+
+    #[test]
+    fn dodges_meteors_from_anywhere_they_spawn() {
+        let spawns = meteor_spawn_range(&VisibleArea::default());
+        for x in (0..).map(|i| spawns.start + i as f32 * 10.0).take_while(|x| spawns.contains(x)) {
+            assert!(survives(&[meteor(x)], 2.0, FRAME_SECS), "hit by a meteor spawned at {x}");
+        }
+    }
+
+    #[test]
+    fn waits_to_jump_a_cactus_until_a_meteor_has_come_down() {
+        let cactus = ground(140.0, Vec2::new(52.0, 85.0), ObstacleKind::Cactus);
+        let hazards = [cactus, meteor(460.0)];
+        // the middle of the window for jumping the cactus alone puts the dino back on the ground under the meteor
+        let dino = running();
+        let alone = Lookahead::new(&dino, &[cactus], FRAME_SECS);
+        let run = alone.first_hit(Plan::Run, LOOKAHEAD_SECS).unwrap();
+        let clear: Vec<f32> = (1..120)
+            .map(|frame| frame as f32 * FRAME_SECS)
+            .filter(|delay| *delay < run && alone.first_hit(Plan::Jump(*delay), LOOKAHEAD_SECS).is_none())
+            .collect();
+        let both = Lookahead::new(&dino, &hazards, FRAME_SECS);
+        assert!(both.first_hit(Plan::Jump(clear[clear.len() / 2]), LOOKAHEAD_SECS).is_some());
+        assert!(survives(&hazards, 3.0, FRAME_SECS));
+    }
+
+    #[test]
+    fn gets_through_a_cactus_then_a_pterodactyl() {
+        let hazards = [
+            ground(300.0, Vec2::new(60.0, 120.0), ObstacleKind::Cactus),
+            pterodactyl(1000.0, 170.0),
+            ground(1500.0, Vec2::new(100.0, 140.0), ObstacleKind::Cactus),
+        ];
+        assert!(survives(&hazards, 4.0, FRAME_SECS));
+    }
+}
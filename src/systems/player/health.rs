@@ -1,23 +1,57 @@
 use crate::components::{
-    AnimationIndices, AnimationTimer, Health, HealthInfo, Player, PlayerCollider, ScoreInfo,
+    AnimationIndices, AnimationTimer, Health, HealthInfo, ObstacleKind, Player, PlayerCollider,
 };
+use crate::events::{ObstacleHit, PlayerDamaged, PlayerDied};
 use crate::constants::DINO_DIE_SIZE;
-use crate::resources::{DinoDie, ScoreOffset};
+use crate::resources::{ActivePowerUps, BaseSpeed, DinoDie};
 use crate::systems::player::animation::animate_die;
 use bevy::prelude::*;
 
+pub fn apply_obstacle_damage(
+    mut events: EventReader<ObstacleHit>,
+    mut health_query: Query<&mut Health, With<PlayerCollider>>,
+    mut power_ups: ResMut<ActivePowerUps>,
+    mut damaged: EventWriter<PlayerDamaged>,
+    mut died: EventWriter<PlayerDied>,
+) {
+    let Ok(mut health) = health_query.single_mut() else {
+        return;
+    };
+    let health_before = health.0;
+    for hit in events.read() {
+        if health.0 == 0 {
+            break;
+        }
+        // a shield charge absorbs the hit
+        if power_ups.absorb_hit() {
+            continue;
+        }
+        // landing in lava is always fatal
+        health.0 = if hit.kind == ObstacleKind::LavaPit { 0 } else { health.0 - 1 };
+        if health.0 == 0 {
+            died.write(PlayerDied { cause: hit.kind });
+        }
+    }
+
+    if health.0 < health_before && health.0 > 0 {
+        damaged.write(PlayerDamaged);
+    }
+}
+
 pub fn check_health(
-    health_query: Query<&Health, With<PlayerCollider>>,
+    mut events: EventReader<PlayerDied>,
     mut player_query: Query<(&mut Sprite, &mut AnimationIndices, &mut AnimationTimer), With<Player>>,
     mut time: ResMut<Time<Virtual>>,
     mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
     mut dino_die: Res<DinoDie>,
+    mut base_speed: ResMut<BaseSpeed>,
 ) {
-    let Health(health) = health_query.single().unwrap();
-    if *health == 0 {
+    if let Some(died) = events.read().last() {
+        info!("dino killed by {:?}", died.cause);
         let (mut sprite, mut anim_indices, mut anim_timer) = player_query.single_mut().unwrap();
         if sprite.custom_size != Some(DINO_DIE_SIZE) {
             // a slow death
+            base_speed.0 = 1.0;
             time.set_relative_speed(1.0);
 
             // switch to dino die animation
@@ -30,18 +64,9 @@ pub fn render_health_info(
     player_query: Query<&mut Health, With<PlayerCollider>>,
     mut health_info_query: Query<&mut Text, With<HealthInfo>>,
 ) {
-    if let Ok(mut health_info) = health_info_query.single_mut() {
-        if let Ok(health) = player_query.single() {
-            health_info.0 = format!("Health: {}", health.0);
-        }
+    if let Ok(mut health_info) = health_info_query.single_mut()
+        && let Ok(health) = player_query.single()
+    {
+        health_info.0 = format!("Health: {}", health.0);
     }
 }
-
-pub fn render_score_info(
-    time: Res<Time<Virtual>>,
-    mut score_info_query: Query<&mut Text, With<ScoreInfo>>,
-    offset: Res<ScoreOffset>,
-) {
-    score_info_query.single_mut().unwrap().0 =
-        format!("\nScore: {}", (time.elapsed_secs() - offset.0).floor());
-}
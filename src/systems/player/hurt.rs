@@ -0,0 +1,54 @@
+use crate::components::{Hurt, Player, Velocity};
+use crate::constants::PLAYER_X;
+use crate::events::PlayerDamaged;
+use crate::resources::HurtConfig;
+use bevy::prelude::*;
+
+// how quickly the dino walks back to its spot after a knockback
+const RECOVERY_SPEED: f32 = 120.0;
+const BLINK_ALPHA: f32 = 0.3;
+
+pub fn start_hurt(
+    mut commands: Commands,
+    mut events: EventReader<PlayerDamaged>,
+    config: Res<HurtConfig>,
+    mut player_query: Query<(Entity, &mut Transform, &mut Velocity), With<Player>>,
+) {
+    // several hits in one frame only knock the dino back once
+    if events.read().last().is_none() {
+        return;
+    }
+    let Ok((entity, mut transform, mut velocity)) = player_query.single_mut() else {
+        return;
+    };
+    transform.translation.x -= config.knockback.x;
+    velocity.0.y = velocity.0.y.max(config.knockback.y);
+    commands.entity(entity).insert(Hurt {
+        timer: Timer::from_seconds(config.invulnerability_secs, TimerMode::Once),
+        blink: Timer::from_seconds(config.blink_interval_secs, TimerMode::Repeating),
+    });
+}
+
+pub fn update_hurt(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut player_query: Query<(Entity, &mut Hurt, &mut Sprite, &mut Transform), With<Player>>,
+) {
+    let Ok((entity, mut hurt, mut sprite, mut transform)) = player_query.single_mut() else {
+        return;
+    };
+    hurt.timer.tick(time.delta());
+    hurt.blink.tick(time.delta());
+
+    transform.translation.x = (transform.translation.x + RECOVERY_SPEED * time.delta_secs()).min(PLAYER_X);
+
+    if hurt.timer.finished() {
+        sprite.color.set_alpha(1.0);
+        commands.entity(entity).remove::<Hurt>();
+        return;
+    }
+
+    // faded for the first half of every blink interval
+    let faded = hurt.blink.elapsed_secs() < hurt.blink.duration().as_secs_f32() / 2.0;
+    sprite.color.set_alpha(if faded { BLINK_ALPHA } else { 1.0 });
+}
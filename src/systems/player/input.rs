@@ -0,0 +1,197 @@
+use crate::components::TouchButton;
+use crate::events::{PauseRequested, PlayerAction};
+use crate::resources::Settings;
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+use bevy::platform::collections::HashMap;
+use bevy::window::PrimaryWindow;
+
+// in logical pixels, how far a finger has to move to count as a swipe
+const SWIPE_DISTANCE: f32 = 50.0;
+// holding the lower half of the screen this long ducks, letting go sooner jumps
+const HOLD_SECS: f32 = 0.15;
+// a second finger landing this soon after the first makes a two-finger tap
+const TWO_FINGER_SECS: f32 = 0.25;
+const BUTTON_SIZE: f32 = 110.0;
+const BUTTON_MARGIN: f32 = 24.0;
+const BUTTON_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.25);
+
+pub fn keyboard_actions(mut events: EventReader<KeyboardInput>, mut actions: EventWriter<PlayerAction>) {
+    for e in events.read().filter(|e| !e.repeat) {
+        match (e.key_code, e.state) {
+            (KeyCode::Space | KeyCode::ArrowUp, ButtonState::Pressed) => {
+                actions.write(PlayerAction::Jump);
+            }
+            (KeyCode::ArrowDown, ButtonState::Pressed) => {
+                actions.write(PlayerAction::StartDuck);
+            }
+            (KeyCode::ArrowDown, ButtonState::Released) => {
+                actions.write(PlayerAction::StopDuck);
+            }
+            _ => {}
+        }
+    }
+}
+
+// what one finger on the screen has done so far
+pub struct TouchGesture {
+    started: f32,
+    lower_half: bool,
+    jumped: bool,
+    ducking: bool,
+    // part of a two-finger tap, or started while paused
+    ignored: bool,
+}
+
+// tap the upper half or swipe up to jump, swipe down or hold the lower half to duck,
+// tap with two fingers to pause or resume
+#[allow(clippy::too_many_arguments)]
+pub fn touch_gestures(
+    touches: Res<Touches>,
+    settings: Res<Settings>,
+    real_time: Res<Time<Real>>,
+    time: Res<Time<Virtual>>,
+    window: Query<&Window, With<PrimaryWindow>>,
+    mut gestures: Local<HashMap<u64, TouchGesture>>,
+    mut actions: EventWriter<PlayerAction>,
+    mut pauses: EventWriter<PauseRequested>,
+) {
+    // the on-screen buttons take over from gestures
+    if settings.touch_buttons {
+        gestures.clear();
+        return;
+    }
+    let now = real_time.elapsed_secs();
+    let half_height = window.single().map(|window| window.height() / 2.0).unwrap_or(f32::MAX);
+
+    let landed = touches.iter_just_pressed().count();
+    let recent = gestures.values().any(|gesture| now - gesture.started < TWO_FINGER_SECS);
+    let two_finger_tap = landed >= 2 || (landed == 1 && recent);
+    if two_finger_tap {
+        // take back what the first finger started
+        for gesture in gestures.values_mut() {
+            stop_ducking(gesture, &mut actions);
+            gesture.ignored = true;
+        }
+        pauses.write(PauseRequested);
+    }
+    for touch in touches.iter_just_pressed() {
+        let mut gesture = TouchGesture {
+            started: now,
+            lower_half: touch.position().y > half_height,
+            jumped: false,
+            ducking: false,
+            ignored: two_finger_tap || time.is_paused(),
+        };
+        // no need to wait and see on the upper half
+        if !gesture.ignored && !gesture.lower_half {
+            gesture.jumped = true;
+            actions.write(PlayerAction::Jump);
+        }
+        gestures.insert(touch.id(), gesture);
+    }
+
+    for touch in touches.iter() {
+        let Some(gesture) = gestures.get_mut(&touch.id()) else {
+            continue;
+        };
+        if gesture.ignored || gesture.ducking {
+            continue;
+        }
+        // screen coordinates grow downwards
+        let moved = touch.distance();
+        let held = now - gesture.started >= HOLD_SECS && moved.length() < SWIPE_DISTANCE;
+        if moved.y > SWIPE_DISTANCE || (gesture.lower_half && !gesture.jumped && held) {
+            gesture.ducking = true;
+            actions.write(PlayerAction::StartDuck);
+        } else if moved.y < -SWIPE_DISTANCE && !gesture.jumped {
+            gesture.jumped = true;
+            actions.write(PlayerAction::Jump);
+        }
+    }
+
+    for touch in touches.iter_just_released().chain(touches.iter_just_canceled()) {
+        let Some(mut gesture) = gestures.remove(&touch.id()) else {
+            continue;
+        };
+        if gesture.ducking {
+            stop_ducking(&mut gesture, &mut actions);
+        } else if !gesture.ignored && !gesture.jumped && touches.just_released(touch.id()) {
+            // a quick tap on the lower half
+            actions.write(PlayerAction::Jump);
+        }
+    }
+    // forget fingers lifted while the game wasn't listening
+    if touches.iter().next().is_none() {
+        gestures.clear();
+    }
+}
+
+fn stop_ducking(gesture: &mut TouchGesture, actions: &mut EventWriter<PlayerAction>) {
+    if gesture.ducking {
+        gesture.ducking = false;
+        actions.write(PlayerAction::StopDuck);
+    }
+}
+
+pub fn touch_button_actions(
+    buttons: Query<(&Interaction, &TouchButton), Changed<Interaction>>,
+    mut actions: EventWriter<PlayerAction>,
+    mut pauses: EventWriter<PauseRequested>,
+) {
+    for (interaction, button) in &buttons {
+        let pressed = *interaction == Interaction::Pressed;
+        match button {
+            TouchButton::Jump if pressed => {
+                actions.write(PlayerAction::Jump);
+            }
+            TouchButton::Duck => {
+                actions.write(if pressed { PlayerAction::StartDuck } else { PlayerAction::StopDuck });
+            }
+            TouchButton::Pause if pressed => {
+                pauses.write(PauseRequested);
+            }
+            _ => {}
+        }
+    }
+}
+
+// shows or hides the on-screen buttons to match the settings
+pub fn sync_touch_buttons(mut commands: Commands, settings: Res<Settings>, buttons: Query<Entity, With<TouchButton>>) {
+    if !settings.is_changed() || settings.touch_buttons != buttons.is_empty() {
+        return;
+    }
+    if !settings.touch_buttons {
+        for entity in &buttons {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+    let corner = Val::Px(BUTTON_MARGIN);
+    let buttons = [
+        (TouchButton::Duck, "DUCK", BUTTON_SIZE, Node { left: corner, bottom: corner, ..default() }),
+        (TouchButton::Jump, "JUMP", BUTTON_SIZE, Node { right: corner, bottom: corner, ..default() }),
+        (TouchButton::Pause, "II", BUTTON_SIZE / 2.0, Node { right: corner, top: corner, ..default() }),
+    ];
+    for (button, label, size, position) in buttons {
+        commands
+            .spawn((
+                button,
+                Button,
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Px(size),
+                    height: Val::Px(size),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..position
+                },
+                BorderRadius::all(Val::Percent(50.)),
+                BackgroundColor(BUTTON_COLOR),
+            ))
+            .with_children(|button| {
+                button.spawn((Text::new(label), TextFont::from_font_size(size / 4.0), TextColor(Color::WHITE)));
+            });
+    }
+}
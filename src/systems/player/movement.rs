@@ -1,18 +1,20 @@
 use crate::components::{
-    AnimationIndices, AnimationTimer, Collider, Player, PlayerCollider, Velocity,
+    AnimationIndices, AnimationTimer, Collider, ParticleKind, Player, PlayerCollider, Velocity,
 };
 use crate::constants::{DINO_DIE_SIZE, DINO_DUCK_SIZE, DINO_JUMP_SIZE, DINO_RUN_IMG_SIZE_X, DINO_RUN_IMG_SIZE_Y, DINO_RUN_SIZE, GROUND_LEVEL, HIT_BOX_SCALE_X};
-use crate::resources::{DinoDuck, DinoJump, DinoRun, RealTimer};
+use crate::events::{ParticleBurst, PlayerAction, PlayerDucked, PlayerJumped, PlayerLanded};
+use crate::resources::{ActivePowerUps, BaseSpeed, CameraEffects, DinoDuck, DinoJump, DinoRun, NearMissFlash, RealTimer};
 use crate::states::GameState;
 use crate::states::GameState::GameOver;
+use crate::components::PowerUpKind;
 use crate::systems::player::animation::{animate_duck, animate_jump, animate_run};
-use bevy::input::keyboard::KeyboardInput;
-use bevy::input::ButtonState;
+use crate::systems::obstacles::near_miss::NEAR_MISS_SLOW_MO_FACTOR;
+use crate::systems::player::power_ups::SLOW_MO_FACTOR;
 use bevy::math::{UVec2, Vec2};
 use bevy::prelude::*;
 
-const JUMP_FORCE: f32 = 1900.0;
-const GRAVITY: f32 = -4000.0;
+pub const JUMP_FORCE: f32 = 1900.0;
+pub const GRAVITY: f32 = -4000.0;
 const MAX_REL_TIME: f32 = 3.0;
 
 #[cfg(debug_assertions)] // Development mode
@@ -21,6 +23,7 @@ const REL_TIME_INCR: f32 = 0.02;
 #[cfg(not(debug_assertions))] // Release mode
 const REL_TIME_INCR: f32 = 0.02;
 
+#[allow(clippy::too_many_arguments)]
 pub fn drop_player(
     time: Res<Time>,
     mut query: Query<
@@ -37,13 +40,25 @@ pub fn drop_player(
     mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
     mut dino_run: Res<DinoRun>,
     mut game_state: ResMut<NextState<GameState>>,
+    mut power_ups: ResMut<ActivePowerUps>,
+    mut bursts: EventWriter<ParticleBurst>,
+    mut landed: EventWriter<PlayerLanded>,
 ) {
     for (mut transform, mut velocity, mut sprite, mut anim_indices, mut anim_timer) in query.iter_mut() {
+        let airborne = transform.translation.y > GROUND_LEVEL;
         transform.translation.y += velocity.0.y * time.delta_secs();
 
         if transform.translation.y <= GROUND_LEVEL {
             transform.translation.y = GROUND_LEVEL;
+            if airborne {
+                landed.write(PlayerLanded);
+                bursts.write(ParticleBurst {
+                    kind: ParticleKind::Dust,
+                    position: Vec2::new(transform.translation.x, GROUND_LEVEL),
+                });
+            }
             velocity.0.y = 0.0;
+            power_ups.air_jump_used = false;
             // back to running if jumping
             if sprite.custom_size == Some(DINO_JUMP_SIZE) {
 
@@ -52,13 +67,12 @@ pub fn drop_player(
 
                 animate_run(&mut dino_run, &mut sprite, &mut anim_indices, &mut anim_timer, &mut texture_atlas_layouts, 4);
                 // not touching the transforms
-            } else if sprite.custom_size == Some(DINO_DIE_SIZE) {
-                if let Some(atlas) = &sprite.texture_atlas {
-                    if atlas.index >= 4 {
-                        // dino dead on the ground, set game state to game over
-                        game_state.set(GameOver);
-                    }
-                }
+            } else if sprite.custom_size == Some(DINO_DIE_SIZE)
+                && let Some(atlas) = &sprite.texture_atlas
+                && atlas.index >= 4
+            {
+                // dino dead on the ground, set game state to game over
+                game_state.set(GameOver);
             }
         }
     }
@@ -71,14 +85,14 @@ pub fn animate_sprite(
     for (indices, mut timer, mut sprite) in &mut query {
         timer.tick(time.delta());
 
-        if timer.just_finished() {
-            if let Some(atlas) = &mut sprite.texture_atlas {
-                atlas.index = if atlas.index >= indices.last {
-                    indices.first
-                } else {
-                    atlas.index + 1
-                };
-            }
+        if timer.just_finished()
+            && let Some(atlas) = &mut sprite.texture_atlas
+        {
+            atlas.index = if atlas.index >= indices.last {
+                indices.first
+            } else {
+                atlas.index + 1
+            };
         }
     }
 }
@@ -89,8 +103,9 @@ pub fn apply_gravity(time: Res<Time>, mut query: Query<&mut Velocity>) {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn jump(
-    mut events: EventReader<KeyboardInput>,
+    mut actions: EventReader<PlayerAction>,
     mut query: Query<
         (
             &mut Velocity,
@@ -101,59 +116,48 @@ pub fn jump(
         ),
         With<Player>,
     >,
-    touches: Res<Touches>,
     mut dino_jump: Res<DinoJump>,
     mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
     mut player_collider: Query<&mut Collider, With<PlayerCollider>>,
     time: Res<Time<Virtual>>,
+    mut power_ups: ResMut<ActivePowerUps>,
+    mut jumped: EventWriter<PlayerJumped>,
 ) {
-    for e in events.read() {
-        if let Ok((mut velocity, transform, mut sprite, mut anim_indices, mut anim_timer)) =
-            query.single_mut()
-        {
-            if e.state.is_pressed()
-                && (e.key_code == KeyCode::Space || e.key_code == KeyCode::ArrowUp)
-                && transform.translation.y <= GROUND_LEVEL
-                && !time.is_paused()
-            {
-                velocity.0.y = JUMP_FORCE;
-                let mut collider = player_collider.single_mut().unwrap();
-                animate_jump(&mut dino_jump, &mut sprite, &mut anim_indices, &mut anim_timer, &mut texture_atlas_layouts, &mut collider);
-            }
-        }
-    }
-    for _touch in touches.iter_just_pressed() {
-        if let Ok((mut velocity, transform, mut sprite, mut anim_indices, mut anim_timer)) =
-            query.single_mut()
+    for action in actions.read() {
+        if *action == PlayerAction::Jump
+            && let Ok((mut velocity, transform, mut sprite, mut anim_indices, mut anim_timer)) =
+                query.single_mut()
+            && !time.is_paused()
+            && (transform.translation.y <= GROUND_LEVEL || power_ups.try_air_jump())
         {
-            if transform.translation.y <= GROUND_LEVEL {
-                velocity.0.y = JUMP_FORCE;
-                let mut collider = player_collider.single_mut().unwrap();
-                animate_jump(&mut dino_jump, &mut sprite, &mut anim_indices, &mut anim_timer, &mut texture_atlas_layouts, &mut collider);
-
-            }
+            velocity.0.y = JUMP_FORCE;
+            let mut collider = player_collider.single_mut().unwrap();
+            animate_jump(&mut dino_jump, &mut sprite, &mut anim_indices, &mut anim_timer, &mut texture_atlas_layouts, &mut collider);
+            jumped.write(PlayerJumped);
         }
     }
 }
 
 pub fn duck(
-    mut events: EventReader<KeyboardInput>,
+    mut actions: EventReader<PlayerAction>,
     mut player_query: Query<&mut Sprite, With<Player>>,
     mut player_collider: Query<(&mut Collider, &mut Transform), With<PlayerCollider>>,
     mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
     dino_run: Res<DinoRun>,
     mut dino_duck: Res<DinoDuck>,
+    mut ducked: EventWriter<PlayerDucked>,
 ) {
-    for e in events.read() {
-        if e.key_code == KeyCode::ArrowDown && e.state == ButtonState::Pressed {
+    for action in actions.read() {
+        if *action == PlayerAction::StartDuck {
             let mut sprite = player_query.single_mut().unwrap();
             // switch to ducking if not already
             if sprite.custom_size != Some(DINO_DUCK_SIZE) {
                 let (mut collider, mut transform) = player_collider.single_mut().unwrap();
                 animate_duck(&mut dino_duck, &mut sprite, &mut texture_atlas_layouts, &mut collider, &mut transform);
+                ducked.write(PlayerDucked);
 
             }
-        } else if e.key_code == KeyCode::ArrowDown && e.state == ButtonState::Released {
+        } else if *action == PlayerAction::StopDuck {
             let mut sprite = player_query.single_mut().unwrap();
             let (mut collider, mut transform) = player_collider.single_mut().unwrap();
 
@@ -180,13 +184,22 @@ pub fn change_time_speed(
     mut time_virtual: ResMut<Time<Virtual>>,
     time_fixed: ResMut<Time<Fixed>>,
     mut timer: ResMut<RealTimer>,
+    mut base_speed: ResMut<BaseSpeed>,
+    power_ups: Res<ActivePowerUps>,
+    near_miss_flash: Res<NearMissFlash>,
+    camera_effects: Res<CameraEffects>,
 ) {
     if !time_virtual.is_paused() {
         timer.0.tick(time_fixed.delta());
 
         if timer.0.finished() {
-            let rel_speed = (time_virtual.relative_speed() + REL_TIME_INCR).min(MAX_REL_TIME);
-            time_virtual.set_relative_speed(rel_speed);
+            base_speed.0 = (base_speed.0 + REL_TIME_INCR).min(MAX_REL_TIME);
         }
+
+        let slow_mo = if power_ups.is_active(PowerUpKind::SlowMo) { SLOW_MO_FACTOR } else { 1.0 };
+        let flash = if near_miss_flash.0.finished() { 1.0 } else { NEAR_MISS_SLOW_MO_FACTOR };
+        // gameplay freezes for a few frames when the dino gets hurt
+        let hit_stop = if camera_effects.hit_stop.finished() { 1.0 } else { 0.0 };
+        time_virtual.set_relative_speed(base_speed.0 * slow_mo * flash * hit_stop);
     }
 }
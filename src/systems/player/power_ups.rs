@@ -0,0 +1,199 @@
+use crate::components::{Health, Player, PlayerCollider, PowerUp, PowerUpInfo, PowerUpKind};
+use crate::events::PickupCollected;
+use crate::resources::{ActivePowerUp, ActivePowerUps};
+use bevy::prelude::*;
+
+pub const SLOW_MO_FACTOR: f32 = 0.6;
+const MAGNET_RADIUS: f32 = 350.0;
+const MAGNET_SPEED: f32 = 900.0;
+const SHIELD_TINT: Color = Color::srgb(0.6, 0.9, 1.0);
+
+// How picking up a power-up that is already active behaves
+enum Stacking {
+    // another charge or level, up to the max, and a fresh timer
+    AddStack { max: u32 },
+    // remaining time grows by the base duration, up to the max
+    Extend { max_secs: f32 },
+    // remaining time restarts at the base duration
+    Refresh,
+}
+
+impl PowerUpKind {
+    pub fn duration_secs(&self) -> f32 {
+        match self {
+            PowerUpKind::Health => 0.0,
+            PowerUpKind::Shield => 12.0,
+            PowerUpKind::Magnet => 8.0,
+            PowerUpKind::SlowMo => 4.0,
+            PowerUpKind::DoubleJump => 10.0,
+            PowerUpKind::ScoreMultiplier => 10.0,
+        }
+    }
+
+    fn stacking(&self) -> Stacking {
+        match self {
+            PowerUpKind::Health => Stacking::Refresh,
+            PowerUpKind::Shield => Stacking::AddStack { max: 3 },
+            PowerUpKind::Magnet => Stacking::Extend { max_secs: 16.0 },
+            PowerUpKind::SlowMo => Stacking::Refresh,
+            PowerUpKind::DoubleJump => Stacking::Extend { max_secs: 20.0 },
+            PowerUpKind::ScoreMultiplier => Stacking::AddStack { max: 3 },
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PowerUpKind::Health => "Health",
+            PowerUpKind::Shield => "Shield",
+            PowerUpKind::Magnet => "Magnet",
+            PowerUpKind::SlowMo => "Slow-mo",
+            PowerUpKind::DoubleJump => "Double jump",
+            PowerUpKind::ScoreMultiplier => "Score",
+        }
+    }
+}
+
+impl ActivePowerUps {
+    pub fn is_active(&self, kind: PowerUpKind) -> bool {
+        self.active.contains_key(&kind)
+    }
+
+    pub fn stacks(&self, kind: PowerUpKind) -> u32 {
+        self.active.get(&kind).map_or(0, |power_up| power_up.stacks)
+    }
+
+    pub fn activate(&mut self, kind: PowerUpKind) {
+        let duration = kind.duration_secs();
+        let Some(power_up) = self.active.get_mut(&kind) else {
+            self.active.insert(kind, ActivePowerUp {
+                timer: Timer::from_seconds(duration, TimerMode::Once),
+                stacks: 1,
+            });
+            return;
+        };
+        match kind.stacking() {
+            Stacking::AddStack { max } => {
+                power_up.stacks = (power_up.stacks + 1).min(max);
+                power_up.timer.reset();
+            }
+            Stacking::Extend { max_secs } => {
+                let remaining = (power_up.timer.remaining_secs() + duration).min(max_secs);
+                power_up.timer = Timer::from_seconds(remaining, TimerMode::Once);
+            }
+            Stacking::Refresh => power_up.timer.reset(),
+        }
+    }
+
+    // uses up a shield charge if there is one, returns whether the hit was absorbed
+    pub fn absorb_hit(&mut self) -> bool {
+        let Some(shield) = self.active.get_mut(&PowerUpKind::Shield) else {
+            return false;
+        };
+        shield.stacks -= 1;
+        if shield.stacks == 0 {
+            self.active.remove(&PowerUpKind::Shield);
+        }
+        true
+    }
+
+    // the extra jump in mid-air granted by double jump, once per landing
+    pub fn try_air_jump(&mut self) -> bool {
+        if self.is_active(PowerUpKind::DoubleJump) && !self.air_jump_used {
+            self.air_jump_used = true;
+            return true;
+        }
+        false
+    }
+
+    pub fn score_multiplier(&self) -> f32 {
+        1.0 + self.stacks(PowerUpKind::ScoreMultiplier) as f32
+    }
+}
+
+pub fn apply_pickups(
+    mut events: EventReader<PickupCollected>,
+    mut health_query: Query<&mut Health, With<PlayerCollider>>,
+    mut power_ups: ResMut<ActivePowerUps>,
+) {
+    for pickup in events.read() {
+        match pickup.kind {
+            PowerUpKind::Health => {
+                if let Ok(mut health) = health_query.single_mut() {
+                    health.0 = health.0.saturating_add(1);
+                }
+            }
+            kind => power_ups.activate(kind),
+        }
+    }
+}
+
+pub fn tick_power_ups(
+    real_time: Res<Time<Real>>,
+    time: Res<Time<Virtual>>,
+    mut power_ups: ResMut<ActivePowerUps>,
+) {
+    if time.is_paused() {
+        return;
+    }
+    // durations are in real seconds, so slow-mo doesn't stretch itself
+    power_ups.active.retain(|_, power_up| {
+        power_up.timer.tick(real_time.delta());
+        !power_up.timer.finished()
+    });
+}
+
+pub fn attract_pickups(
+    time: Res<Time>,
+    power_ups: Res<ActivePowerUps>,
+    player_query: Query<&GlobalTransform, With<PlayerCollider>>,
+    mut pickups: Query<&mut Transform, With<PowerUp>>,
+) {
+    if !power_ups.is_active(PowerUpKind::Magnet) {
+        return;
+    }
+    let Ok(player) = player_query.single() else {
+        return;
+    };
+    let target = player.translation().truncate();
+    for mut transform in pickups.iter_mut() {
+        let offset = target - transform.translation.truncate();
+        if offset.length() < MAGNET_RADIUS {
+            let step = offset.normalize_or_zero() * MAGNET_SPEED * time.delta_secs();
+            transform.translation += step.clamp_length_max(offset.length()).extend(0.0);
+        }
+    }
+}
+
+pub fn tint_shielded_player(
+    power_ups: Res<ActivePowerUps>,
+    mut player_query: Query<&mut Sprite, With<Player>>,
+) {
+    if let Ok(mut sprite) = player_query.single_mut() {
+        sprite.color = if power_ups.is_active(PowerUpKind::Shield) {
+            SHIELD_TINT
+        } else {
+            Color::WHITE
+        };
+    }
+}
+
+pub fn render_power_up_info(
+    power_ups: Res<ActivePowerUps>,
+    mut power_up_info_query: Query<&mut Text, With<PowerUpInfo>>,
+) {
+    if let Ok(mut power_up_info) = power_up_info_query.single_mut() {
+        let timers: Vec<String> = power_ups
+            .active
+            .iter()
+            .map(|(kind, power_up)| {
+                let secs = power_up.timer.remaining_secs().ceil();
+                match kind {
+                    PowerUpKind::Shield => format!("{} x{} {}s", kind.label(), power_up.stacks, secs),
+                    PowerUpKind::ScoreMultiplier => format!("{} x{} {}s", kind.label(), power_up.stacks + 1, secs),
+                    _ => format!("{} {}s", kind.label(), secs),
+                }
+            })
+            .collect();
+        power_up_info.0 = format!("\n\n{}", timers.join("  "));
+    }
+}
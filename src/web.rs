@@ -0,0 +1,143 @@
+use crate::components::PauseText;
+use crate::no_menu_open;
+use crate::events::RestartGame;
+use crate::options::GameOptions;
+use crate::resources::{Controller, Score, Settings};
+use crate::states::GameState;
+use crate::systems::game::pause::show_pause_text;
+use bevy::prelude::*;
+use js_sys::Function;
+use std::cell::{Cell, RefCell};
+use std::thread::LocalKey;
+use wasm_bindgen::prelude::*;
+
+// the JavaScript API for pages that embed the game
+
+enum HostCommand {
+    Pause,
+    Resume,
+    Restart,
+}
+
+// wasm is single threaded, so the page and the game share these without locking
+thread_local! {
+    static STARTED: Cell<bool> = const { Cell::new(false) };
+    static COMMANDS: RefCell<Vec<HostCommand>> = const { RefCell::new(Vec::new()) };
+    static ON_SCORE: RefCell<Option<Function>> = const { RefCell::new(None) };
+    static ON_GAME_OVER: RefCell<Option<Function>> = const { RefCell::new(None) };
+}
+
+// options is an object like { seed: 42, difficulty: "hard", canvasId: "game", assetBaseUrl: "https://cdn.example.com/dino" },
+// every field is optional
+#[wasm_bindgen]
+pub fn start(options: JsValue) -> Result<(), JsValue> {
+    console_error_panic_hook::set_once();
+    if STARTED.replace(true) {
+        return Err("the game has already started".into());
+    }
+    let options: GameOptions = if options.is_undefined() || options.is_null() {
+        GameOptions::default()
+    } else {
+        let json = js_sys::JSON::stringify(&options)?.as_string().unwrap_or_default();
+        serde_json::from_str(&json).map_err(|err| format!("invalid game options: {err}"))?
+    };
+    crate::run(options);
+    Ok(())
+}
+
+#[wasm_bindgen]
+pub fn pause() {
+    COMMANDS.with_borrow_mut(|commands| commands.push(HostCommand::Pause));
+}
+
+#[wasm_bindgen]
+pub fn resume() {
+    COMMANDS.with_borrow_mut(|commands| commands.push(HostCommand::Resume));
+}
+
+#[wasm_bindgen]
+pub fn restart() {
+    COMMANDS.with_borrow_mut(|commands| commands.push(HostCommand::Restart));
+}
+
+// called with the score whenever it changes
+#[wasm_bindgen(js_name = onScore)]
+pub fn on_score(callback: Function) {
+    ON_SCORE.set(Some(callback));
+}
+
+// called with the final score when the dino dies
+#[wasm_bindgen(js_name = onGameOver)]
+pub fn on_game_over(callback: Function) {
+    ON_GAME_OVER.set(Some(callback));
+}
+
+fn call(callback: &'static LocalKey<RefCell<Option<Function>>>, score: u32) {
+    callback.with_borrow(|callback| {
+        if let Some(callback) = callback
+            && let Err(err) = callback.call1(&JsValue::NULL, &score.into())
+        {
+            error!("host callback failed: {err:?}");
+        }
+    });
+}
+
+pub fn add_host_systems(app: &mut App) {
+    // the autopilot demo behind the main menu doesn't count
+    // like key presses, the page's commands are dropped while a menu holds up the game
+    app.add_systems(
+        Update,
+        (
+            apply_host_commands.run_if(no_menu_open),
+            drop_host_commands.run_if(not(no_menu_open)),
+            report_score.run_if(resource_equals(Controller::Human)),
+        ),
+    )
+        .add_systems(OnEnter(GameState::GameOver), report_game_over.run_if(resource_equals(Controller::Human)));
+}
+
+fn apply_host_commands(
+    mut commands: Commands,
+    state: Res<State<GameState>>,
+    mut time: ResMut<Time<Virtual>>,
+    pause_text: Query<Entity, With<PauseText>>,
+    settings: Res<Settings>,
+    mut restarts: EventWriter<RestartGame>,
+) {
+    // the game over screen keeps time paused, so pausing only applies to a run in progress
+    let running = *state.get() == GameState::InGame;
+    for command in COMMANDS.take() {
+        match command {
+            HostCommand::Pause if running && !time.is_paused() => {
+                time.pause();
+                show_pause_text(commands.reborrow(), &settings);
+            }
+            HostCommand::Resume if running && time.is_paused() => {
+                time.unpause();
+                for entity in &pause_text {
+                    commands.entity(entity).try_despawn();
+                }
+            }
+            HostCommand::Restart => {
+                restarts.write(RestartGame);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn drop_host_commands() {
+    COMMANDS.take();
+}
+
+fn report_score(score: Res<Score>, mut reported: Local<Option<u32>>) {
+    let total = score.total();
+    if *reported != Some(total) {
+        *reported = Some(total);
+        call(&ON_SCORE, total);
+    }
+}
+
+fn report_game_over(score: Res<Score>) {
+    call(&ON_GAME_OVER, score.total());
+}